@@ -0,0 +1,284 @@
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Flex, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use std::path::Path;
+
+use ::wordle_engine::{LetterResponse, StatsStore, WordList, WordleEngine};
+
+use crate::{save_game, Cli};
+
+const KEYBOARD: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// The width of the board, which fits the keyboard
+const WIDTH: u16 = 42;
+
+struct Game<'a> {
+    cli: &'a Cli,
+    guesses: &'a WordList,
+    engine: WordleEngine,
+    stats: &'a mut StatsStore,
+    stats_path: &'a Path,
+    /// Where the game is saved after each guess, so it can be resumed after quitting
+    save_path: &'a Path,
+    /// The letters typed so far for the next guess
+    guess: String,
+    /// Why the last guess was rejected, or how the game ended
+    message: String,
+    /// The share text of every finished game
+    shares: Vec<String>,
+}
+
+fn tile_style(response: Option<LetterResponse>) -> Style {
+    let style = Style::new().add_modifier(Modifier::BOLD);
+    match response {
+        Some(LetterResponse::Correct) => style.fg(Color::Black).bg(Color::Green),
+        Some(LetterResponse::Misplaced) => style.fg(Color::Black).bg(Color::Yellow),
+        Some(LetterResponse::Absent) => style.fg(Color::White).bg(Color::DarkGray),
+        None => style,
+    }
+}
+
+fn tile(letter: char, style: Style) -> [Span<'static>; 2] {
+    [
+        Span::styled(format!(" {} ", letter.to_ascii_uppercase()), style),
+        Span::raw(" "),
+    ]
+}
+
+impl Game<'_> {
+    fn handle_key(&mut self, code: KeyCode) -> io::Result<()> {
+        let length = self.engine.get_solution().len();
+        match code {
+            KeyCode::Enter if self.engine.is_over() => {
+                self.engine = self.cli.new_game(self.guesses, None);
+                self.message.clear();
+            }
+            KeyCode::Enter => {
+                self.message = match self.engine.try_guess(&self.guess) {
+                    Ok(_) => {
+                        self.guess.clear();
+                        save_game(&self.engine, self.save_path)?;
+                        String::new()
+                    }
+                    Err(e) => e.to_string(),
+                };
+                if self.engine.is_over() {
+                    self.finish()?;
+                }
+            }
+            KeyCode::Backspace => {
+                self.guess.pop();
+            }
+            KeyCode::Char(c)
+                if c.is_ascii_alphabetic()
+                    && self.guess.len() < length
+                    && !self.engine.is_over() =>
+            {
+                self.guess.push(c.to_ascii_lowercase());
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Record the finished game
+    fn finish(&mut self) -> io::Result<()> {
+        self.stats.record(&self.engine);
+        self.stats.save(self.stats_path)?;
+        self.shares.push(self.engine.share());
+        self.message = if self.engine.solved() {
+            match self.engine.history().len() {
+                1 => "Genius",
+                2 => "Magnificent",
+                3 => "Impressive",
+                4 => "Splendid",
+                5 => "Great",
+                _ => "Phew",
+            }
+            .to_string()
+        } else {
+            format!(
+                "The answer was {}",
+                self.engine.get_solution().to_uppercase()
+            )
+        };
+        Ok(())
+    }
+
+    fn board_lines(&self) -> Vec<Line<'static>> {
+        let length = self.engine.get_solution().len();
+        let mut lines: Vec<Line> = self
+            .engine
+            .history()
+            .iter()
+            .map(|(guess, response)| {
+                Line::from(
+                    guess
+                        .chars()
+                        .zip(&response.0)
+                        .flat_map(|(c, &r)| tile(c, tile_style(Some(r))))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        if !self.engine.is_over() {
+            let typed = self.guess.chars().map(|c| tile(c, tile_style(None)));
+            let empty = (self.guess.len()..length).map(|_| tile('_', tile_style(None)));
+            lines.push(Line::from(typed.chain(empty).flatten().collect::<Vec<_>>()));
+        }
+        while lines.len() < self.engine.max_guesses() {
+            lines.push(Line::from(
+                (0..length)
+                    .flat_map(|_| tile('·', Style::new().fg(Color::DarkGray)))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+        lines
+    }
+
+    fn keyboard_lines(&self) -> Vec<Line<'static>> {
+        KEYBOARD
+            .iter()
+            .map(|row| {
+                Line::from(
+                    row.chars()
+                        .flat_map(|c| tile(c, tile_style(self.engine.letter_response(c))))
+                        .collect::<Vec<_>>(),
+                )
+                .centered()
+            })
+            .collect()
+    }
+
+    fn stats_lines(&self) -> Vec<Line<'static>> {
+        let summary = self.stats.summary();
+        let mut lines = vec![
+            Line::from(format!(
+                "Played {}  Win % {}  Streak {}  Max {}",
+                summary.played,
+                summary.win_percentage(),
+                summary.current_streak,
+                summary.max_streak
+            )),
+            Line::default(),
+        ];
+        let most = summary.distribution.values().max().cloned().unwrap_or(0);
+        for guesses in 1..=self.engine.max_guesses() {
+            let count = summary.distribution.get(&guesses).cloned().unwrap_or(0);
+            let width = (count * (WIDTH as usize - 12))
+                .checked_div(most)
+                .unwrap_or(0);
+            let style = if self.engine.solved() && self.engine.history().len() == guesses {
+                tile_style(Some(LetterResponse::Correct))
+            } else {
+                tile_style(Some(LetterResponse::Absent))
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("{} ", guesses)),
+                Span::styled(format!("{}{:>2}", " ".repeat(width), count), style),
+            ]));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from("Enter for a new game, Esc to quit").centered());
+        lines
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let rows = self.engine.max_guesses() as u16;
+        let stats_height = if self.engine.is_over() { rows + 6 } else { 0 };
+        let [column] = Layout::horizontal([Constraint::Length(WIDTH)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [board, message, keyboard, stats] = Layout::vertical([
+            Constraint::Length(rows + 2),
+            Constraint::Length(1),
+            Constraint::Length(5),
+            Constraint::Length(stats_height),
+        ])
+        .areas(column);
+        let title = if self.engine.hard_mode() {
+            " Wordle (hard mode) "
+        } else {
+            " Wordle "
+        };
+        frame.render_widget(
+            Paragraph::new(
+                self.board_lines()
+                    .into_iter()
+                    .map(Line::centered)
+                    .collect::<Vec<_>>(),
+            )
+            .block(Block::bordered().title(title)),
+            board,
+        );
+        frame.render_widget(
+            Paragraph::new(Line::styled(
+                self.message.as_str(),
+                Style::new().add_modifier(Modifier::BOLD),
+            ))
+            .centered(),
+            message,
+        );
+        frame.render_widget(
+            Paragraph::new(self.keyboard_lines()).block(Block::bordered()),
+            keyboard,
+        );
+        if self.engine.is_over() {
+            frame.render_widget(
+                Paragraph::new(self.stats_lines()).block(Block::bordered().title(" Statistics ")),
+                stats,
+            );
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                let quit = key.code == KeyCode::Esc
+                    || (key.modifiers.contains(KeyModifiers::CONTROL)
+                        && key.code == KeyCode::Char('c'));
+                if quit {
+                    return Ok(());
+                }
+                self.handle_key(key.code)?;
+            }
+        }
+    }
+}
+
+/// Play games on a coloured board until the player quits, resuming the saved game if there is
+/// one, and returning the share text of every finished game
+pub fn run(
+    cli: &Cli,
+    guesses: &WordList,
+    stats: &mut StatsStore,
+    stats_path: &Path,
+    save_path: &Path,
+) -> io::Result<Vec<String>> {
+    let mut game = Game {
+        cli,
+        guesses,
+        engine: cli.first_game(guesses, save_path)?,
+        stats,
+        stats_path,
+        save_path,
+        guess: String::new(),
+        message: String::new(),
+        shares: Vec::new(),
+    };
+    if !game.engine.history().is_empty() {
+        game.message = "Resumed your saved game".to_string();
+    }
+    let mut terminal = ratatui::init();
+    let result = game.run(&mut terminal);
+    ratatui::restore();
+    result.map(|_| game.shares)
+}
@@ -0,0 +1,227 @@
+use clap::Parser;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use ::wordle_engine::{embedded, StatsStore, WordList, WordleEngine, MAX_GUESSES};
+
+mod game;
+
+/// Play Wordle in the terminal
+#[derive(Parser)]
+#[command(name = "wordle-engine")]
+struct Cli {
+    /// Require every guess to use all the hints revealed so far
+    #[arg(long)]
+    hard_mode: bool,
+    /// The number of guesses allowed
+    #[arg(
+        long,
+        default_value_t = MAX_GUESSES,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    max_guesses: usize,
+    /// Play the first game against this answer, instead of resuming the saved game or picking a
+    /// random answer
+    #[arg(long)]
+    answer: Option<String>,
+    /// File to keep statistics in between sessions [default: wordle-engine/stats.csv in the
+    /// user's data directory]
+    #[arg(long, value_name = "FILE")]
+    stats: Option<PathBuf>,
+    /// File to save an unfinished game to, so it's resumed next time [default:
+    /// wordle-engine/game.json in the user's data directory]
+    #[arg(long, value_name = "FILE")]
+    saved_game: Option<PathBuf>,
+    /// Write the statistics to a file, or "-" for standard output, instead of playing
+    #[arg(long, value_name = "FILE")]
+    export: Option<PathBuf>,
+    /// Add the games from a file written by --export to the statistics, instead of playing
+    #[arg(long, value_name = "FILE", conflicts_with = "export")]
+    import: Option<PathBuf>,
+    /// Prompt for each guess on its own line instead of showing the game board. This is always
+    /// done when input isn't from a terminal.
+    #[arg(long)]
+    plain: bool,
+}
+
+impl Cli {
+    /// Start a game against the given answer, or a random one
+    fn new_game(&self, guesses: &WordList, answer: Option<&str>) -> WordleEngine {
+        let engine = match answer {
+            Some(answer) => WordleEngine::with_answer(guesses.clone(), answer),
+            None => WordleEngine::new(guesses.clone(), embedded::answers()),
+        };
+        engine
+            .with_hard_mode(self.hard_mode)
+            .with_max_guesses(self.max_guesses)
+    }
+
+    /// Resume the saved game unless an answer was given, otherwise start a new one
+    fn first_game(&self, guesses: &WordList, save_path: &Path) -> io::Result<WordleEngine> {
+        if self.answer.is_none() {
+            if let Some(engine) = load_game(guesses, save_path)? {
+                return Ok(engine);
+            }
+        }
+        Ok(self.new_game(guesses, self.answer.as_deref()))
+    }
+}
+
+/// The path of a file kept between sessions: in wordle-engine in the user's data directory if
+/// there is one, otherwise prefixed with "wordle-" in the current directory
+fn data_path(name: &str) -> PathBuf {
+    let data_dir = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from));
+    match data_dir {
+        Some(dir) => dir.join("wordle-engine").join(name),
+        None => PathBuf::from(format!("wordle-{}", name)),
+    }
+}
+
+fn remove_saved_game(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Save an unfinished game to resume next time, or remove the saved game if there's nothing to
+/// resume
+fn save_game(engine: &WordleEngine, path: &Path) -> io::Result<()> {
+    if engine.is_over() || engine.history().is_empty() {
+        remove_saved_game(path)
+    } else {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        engine.save(&mut writer)?;
+        writer.flush()
+    }
+}
+
+/// The game saved by [`save_game`], if there is one
+fn load_game(guesses: &WordList, path: &Path) -> io::Result<Option<WordleEngine>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    WordleEngine::load(BufReader::new(file), guesses.clone())
+        .map(Some)
+        .map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Couldn't resume the game saved in {}, delete it to start a new game: {}",
+                    path.display(),
+                    e
+                ),
+            )
+        })
+}
+
+/// Play by prompting for each guess on its own line, saving the game after each guess so it can
+/// be resumed if input ends. Returns true if the player gave up.
+fn play_plain(engine: &mut WordleEngine, save_path: &Path) -> io::Result<bool> {
+    let mut guess = String::new();
+    for (guess, response) in engine.history() {
+        println!("{} {}", guess, response);
+    }
+    while !engine.is_over() {
+        println!(
+            "Please make a guess, {} left (leave blank to forfeit):",
+            engine.max_guesses() - engine.history().len()
+        );
+        guess.clear();
+        if io::stdin().read_line(&mut guess)? == 0 {
+            if !engine.history().is_empty() {
+                println!("Saved the game to resume next time");
+            }
+            return Ok(false);
+        }
+        if guess.trim().is_empty() {
+            println!("You gave up :(");
+            println!("The answer was {}", engine.get_solution());
+            remove_saved_game(save_path)?;
+            return Ok(true);
+        }
+        match engine.try_guess(&guess.trim().to_lowercase()) {
+            Err(e) => println!("{}", e),
+            Ok(response) => {
+                println!("{}", response);
+                save_game(engine, save_path)?;
+            }
+        }
+    }
+    if !engine.solved() {
+        println!("The answer was {}", engine.get_solution());
+    }
+    Ok(false)
+}
+
+fn run(cli: Cli) -> io::Result<()> {
+    let guesses = WordList::from_words(
+        embedded::guesses().iter().chain(embedded::answers().iter()),
+        embedded::answers().word_length(),
+    )?;
+    if let Some(answer) = &cli.answer {
+        if !guesses.contains(answer) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} isn't in the word list", answer),
+            ));
+        }
+    }
+    let stats_path = cli.stats.clone().unwrap_or_else(|| data_path("stats.csv"));
+    let save_path = cli
+        .saved_game
+        .clone()
+        .unwrap_or_else(|| data_path("game.json"));
+    let mut stats = StatsStore::load(&stats_path)?;
+    if let Some(path) = &cli.export {
+        if path.as_os_str() == "-" {
+            stats.export(io::stdout().lock())
+        } else {
+            let mut writer = BufWriter::new(File::create(path)?);
+            stats.export(&mut writer)?;
+            writer.flush()
+        }
+    } else if let Some(path) = &cli.import {
+        let added = stats.import(BufReader::new(File::open(path)?))?;
+        stats.save(&stats_path)?;
+        println!("Added {} games, {} in total", added, stats.records().len());
+        Ok(())
+    } else if cli.plain || !io::stdin().is_terminal() {
+        let mut engine = cli.first_game(&guesses, &save_path)?;
+        let forfeited = play_plain(&mut engine, &save_path)?;
+        if forfeited || engine.is_over() {
+            if forfeited {
+                stats.record_forfeit(&engine);
+            } else {
+                stats.record(&engine);
+            }
+            stats.save(&stats_path)?;
+            println!("{}\n\n{}", engine.share(), stats.summary());
+        }
+        Ok(())
+    } else {
+        let shares = game::run(&cli, &guesses, &mut stats, &stats_path, &save_path)?;
+        if let Some(share) = shares.last() {
+            println!("{}", share);
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,35 @@
+//! Word lists compiled into the crate, so they're available regardless of the working directory.
+
+use std::sync::OnceLock;
+
+use crate::WordList;
+
+static ANSWERS: OnceLock<WordList> = OnceLock::new();
+static GUESSES: OnceLock<WordList> = OnceLock::new();
+
+/// The list of words which can be chosen as answers
+pub fn answers() -> &'static WordList {
+    ANSWERS.get_or_init(|| {
+        WordList::from_reader(include_str!("../possible-answers.txt").as_bytes())
+            .expect("Embedded answer list is invalid")
+    })
+}
+
+/// The list of words which are accepted as guesses
+pub fn guesses() -> &'static WordList {
+    GUESSES.get_or_init(|| {
+        WordList::from_reader(include_str!("../possible-guesses.txt").as_bytes())
+            .expect("Embedded guess list is invalid")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_lists_load() {
+        assert!(answers().contains("aback"));
+        assert!(guesses().contains("women"));
+    }
+}
@@ -0,0 +1,369 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::str::FromStr;
+
+use crate::jotto::shared_letters;
+use crate::{HardModeViolation, LetterResponse, WordleEngine, WordleResponse};
+
+/// The rules for the feedback a guess gets, for playing and solving games other than standard
+/// Wordle.
+///
+/// Solvers split up the possible answers by the [`FeedbackModel::index`] of the pattern each
+/// would give, so every pattern for words of a length must have a different index below
+/// [`FeedbackModel::pattern_count`].
+pub trait FeedbackModel {
+    /// The feedback shown for a guess
+    type Pattern: Clone + Debug + Eq + Display;
+
+    /// The feedback for guessing `guess` when the answer is `answer`
+    fn feedback(&self, guess: &str, answer: &str) -> Self::Pattern;
+
+    /// The number identifying the pattern, below [`FeedbackModel::pattern_count`]
+    fn index(&self, pattern: &Self::Pattern) -> usize;
+
+    /// The number of different patterns for words of the given length
+    fn pattern_count(&self, word_length: usize) -> usize;
+
+    /// The index of the feedback for guessing `guess` when the answer is `answer`
+    fn feedback_index(&self, guess: &str, answer: &str) -> usize {
+        self.index(&self.feedback(guess, answer))
+    }
+
+    /// The first hint revealed by the feedback to `guess` which `word` doesn't use, for models
+    /// which support hard mode
+    fn hard_mode_violation(
+        &self,
+        _guess: &str,
+        _pattern: &Self::Pattern,
+        _word: &str,
+    ) -> Option<HardModeViolation> {
+        None
+    }
+}
+
+/// Standard Wordle feedback: green for the right letter in the right place, yellow for a letter
+/// elsewhere in the answer, counting repeated letters only as often as the answer has them
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Standard;
+
+impl FeedbackModel for Standard {
+    type Pattern = WordleResponse;
+
+    fn feedback(&self, guess: &str, answer: &str) -> WordleResponse {
+        WordleEngine::get_response(answer, guess)
+    }
+
+    fn index(&self, pattern: &WordleResponse) -> usize {
+        pattern.index()
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        3usize.pow(word_length as u32)
+    }
+
+    fn feedback_index(&self, guess: &str, answer: &str) -> usize {
+        // Solvers call this for every pair of guess and answer, so ASCII words are scored from
+        // letter counts without building the response
+        if !guess.is_ascii() || !answer.is_ascii() {
+            return self.index(&self.feedback(guess, answer));
+        }
+        let (guess, answer) = (guess.as_bytes(), answer.as_bytes());
+        let mut unmatched = [0u8; 128];
+        for (i, &ac) in answer.iter().enumerate() {
+            if guess.get(i) != Some(&ac) {
+                unmatched[ac as usize] += 1;
+            }
+        }
+        guess.iter().enumerate().fold(0, |acc, (i, &gc)| {
+            acc * 3
+                + if answer.get(i) == Some(&gc) {
+                    2
+                } else if unmatched[gc as usize] > 0 {
+                    unmatched[gc as usize] -= 1;
+                    1
+                } else {
+                    0
+                }
+        })
+    }
+
+    fn hard_mode_violation(
+        &self,
+        guess: &str,
+        pattern: &WordleResponse,
+        word: &str,
+    ) -> Option<HardModeViolation> {
+        pattern.hard_mode_violation(guess, word)
+    }
+}
+
+/// Only the greens of standard feedback, so misplaced letters look absent
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct GreensOnly;
+
+impl FeedbackModel for GreensOnly {
+    type Pattern = WordleResponse;
+
+    fn feedback(&self, guess: &str, answer: &str) -> WordleResponse {
+        WordleResponse(
+            guess
+                .chars()
+                .zip(answer.chars())
+                .map(|(gc, ac)| {
+                    if gc == ac {
+                        LetterResponse::Correct
+                    } else {
+                        LetterResponse::Absent
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn index(&self, pattern: &WordleResponse) -> usize {
+        pattern.0.iter().fold(0, |acc, &letter| {
+            acc * 2 + (letter == LetterResponse::Correct) as usize
+        })
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        2usize.pow(word_length as u32)
+    }
+}
+
+/// The number of greens and yellows of standard feedback, without saying which letters they're
+/// for, like the black and white pegs of Mastermind
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct CountOnly;
+
+/// The feedback of [`CountOnly`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LetterCounts {
+    pub correct: usize,
+    pub misplaced: usize,
+}
+
+impl Display for LetterCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} correct, {} misplaced", self.correct, self.misplaced)
+    }
+}
+
+impl FeedbackModel for CountOnly {
+    type Pattern = LetterCounts;
+
+    fn feedback(&self, guess: &str, answer: &str) -> LetterCounts {
+        // Every letter the words share is either green or yellow
+        let correct = guess
+            .chars()
+            .zip(answer.chars())
+            .filter(|(gc, ac)| gc == ac)
+            .count();
+        LetterCounts {
+            correct,
+            misplaced: shared_letters(guess, answer) - correct,
+        }
+    }
+
+    fn index(&self, pattern: &LetterCounts) -> usize {
+        // There are at most as many greens and yellows together as there are letters, so the
+        // patterns are numbered by that total, then by the number of greens
+        let total = pattern.correct + pattern.misplaced;
+        total * (total + 1) / 2 + pattern.correct
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        (word_length + 1) * (word_length + 2) / 2
+    }
+}
+
+/// Wordle Peaks feedback: each letter is either correct, or the answer's letter in that place is
+/// earlier or later in the alphabet
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Peaks;
+
+/// A game of Wordle Peaks, started with [`WordleEngine::with_feedback`]
+pub type PeaksEngine = WordleEngine<Peaks>;
+
+/// The feedback for one letter in Wordle Peaks
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PeaksLetter {
+    Correct,
+    /// The answer's letter is earlier in the alphabet
+    Earlier,
+    /// The answer's letter is later in the alphabet
+    Later,
+}
+
+/// The feedback of [`Peaks`]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PeaksResponse(pub Vec<PeaksLetter>);
+
+impl PeaksResponse {
+    pub fn is_correct(&self) -> bool {
+        self.0.iter().all(|&letter| letter == PeaksLetter::Correct)
+    }
+}
+
+impl Display for PeaksResponse {
+    /// Formats the response with `!` for correct, `<` for an earlier letter and `>` for a later
+    /// one
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for letter in &self.0 {
+            write!(
+                f,
+                "{}",
+                match letter {
+                    PeaksLetter::Correct => '!',
+                    PeaksLetter::Earlier => '<',
+                    PeaksLetter::Later => '>',
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PeaksResponse {
+    type Err = ParsePeaksError;
+
+    /// Parses the format written by `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| match c {
+                '!' => Ok(PeaksLetter::Correct),
+                '<' => Ok(PeaksLetter::Earlier),
+                '>' => Ok(PeaksLetter::Later),
+                c => Err(ParsePeaksError(c)),
+            })
+            .collect::<Result<_, _>>()
+            .map(PeaksResponse)
+    }
+}
+
+/// An error from parsing a [`PeaksResponse`], containing the unexpected character
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParsePeaksError(pub char);
+
+impl Display for ParsePeaksError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unexpected {:?} in response, use '!' for correct, '<' for earlier, '>' for later",
+            self.0
+        )
+    }
+}
+
+impl Error for ParsePeaksError {}
+
+impl FeedbackModel for Peaks {
+    type Pattern = PeaksResponse;
+
+    fn feedback(&self, guess: &str, answer: &str) -> PeaksResponse {
+        PeaksResponse(
+            guess
+                .chars()
+                .zip(answer.chars())
+                .map(|(gc, ac)| match ac.cmp(&gc) {
+                    std::cmp::Ordering::Equal => PeaksLetter::Correct,
+                    std::cmp::Ordering::Less => PeaksLetter::Earlier,
+                    std::cmp::Ordering::Greater => PeaksLetter::Later,
+                })
+                .collect(),
+        )
+    }
+
+    fn index(&self, pattern: &PeaksResponse) -> usize {
+        pattern.0.iter().fold(0, |acc, letter| {
+            acc * 3
+                + match letter {
+                    PeaksLetter::Earlier => 0,
+                    PeaksLetter::Later => 1,
+                    PeaksLetter::Correct => 2,
+                }
+        })
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        3usize.pow(word_length as u32)
+    }
+}
+
+/// The rule some Wordle clones use for repeated letters: every letter of the guess which isn't
+/// green is yellow if the answer has it anywhere, however many times the guess repeats it
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Naive;
+
+impl FeedbackModel for Naive {
+    type Pattern = WordleResponse;
+
+    fn feedback(&self, guess: &str, answer: &str) -> WordleResponse {
+        WordleResponse(
+            guess
+                .chars()
+                .zip(answer.chars())
+                .map(|(gc, ac)| {
+                    if gc == ac {
+                        LetterResponse::Correct
+                    } else if answer.contains(gc) {
+                        LetterResponse::Misplaced
+                    } else {
+                        LetterResponse::Absent
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn index(&self, pattern: &WordleResponse) -> usize {
+        pattern.index()
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        3usize.pow(word_length as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that every pattern for the words has a different index in range
+    fn check_indices<F: FeedbackModel>(model: F, words: &[&str]) {
+        let mut seen = std::collections::HashMap::new();
+        for guess in words {
+            for answer in words {
+                let pattern = model.feedback(guess, answer);
+                let index = model.index(&pattern);
+                assert!(index < model.pattern_count(guess.len()));
+                assert_eq!(model.feedback_index(guess, answer), index);
+                assert_eq!(seen.entry(index).or_insert(pattern.clone()), &pattern);
+            }
+        }
+    }
+
+    #[test]
+    fn test_models() {
+        let words = ["geese", "eerie", "sheep", "tepee", "agree"];
+        check_indices(Standard, &words);
+        check_indices(GreensOnly, &words);
+        check_indices(CountOnly, &words);
+        check_indices(Peaks, &words);
+        check_indices(Naive, &words);
+        check_indices(Standard, &["12+3=15", "15-3=12", "3*4=012"]);
+
+        assert_eq!(Standard.feedback("eerie", "sheep").to_string(), "??...");
+        assert_eq!(Naive.feedback("eerie", "sheep").to_string(), "??..?");
+        assert_eq!(GreensOnly.feedback("geese", "agree").to_string(), "....!");
+        assert_eq!(
+            CountOnly.feedback("geese", "agree"),
+            LetterCounts {
+                correct: 1,
+                misplaced: 2
+            }
+        );
+        assert_eq!(Peaks.feedback("sheep", "agree").to_string(), "<<>!<");
+        assert_eq!("<<>!<".parse(), Ok(Peaks.feedback("sheep", "agree")));
+    }
+}
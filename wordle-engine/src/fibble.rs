@@ -0,0 +1,148 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::{GuessError, LetterResponse, WordleEngine, WordleResponse};
+
+/// A game of Fibble: Wordle where exactly one tile of each response is a lie, showing a colour
+/// other than the true one. Guessing the answer gets a truthful response, so the player knows
+/// they've won.
+pub struct FibbleEngine {
+    engine: WordleEngine,
+    rng: StdRng,
+    /// The responses shown to the player, with the lies
+    history: Vec<(String, WordleResponse)>,
+    /// The position of the lie in each response, or `None` for a correct guess
+    lies: Vec<Option<usize>>,
+}
+
+impl FibbleEngine {
+    /// Play the engine's game with a lie in each response, chosen at random. Hard mode is turned
+    /// off, since checking guesses against the true responses would give the lies away.
+    pub fn new(engine: WordleEngine) -> Self {
+        FibbleEngine {
+            engine: engine.with_hard_mode(false),
+            rng: StdRng::from_entropy(),
+            history: Vec::new(),
+            lies: Vec::new(),
+        }
+    }
+
+    /// Choose the lies with a random number generator seeded with the given number, so the same
+    /// guesses always get the same responses
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Like [`WordleEngine::try_guess`], but with one tile of the response changed to a colour it
+    /// isn't, unless the guess is correct. A lie never makes the whole response green.
+    pub fn try_guess(&mut self, word: &str) -> Result<WordleResponse, GuessError> {
+        let mut response = self.engine.try_guess(word)?;
+        let lie = if response.is_correct() {
+            None
+        } else {
+            let position = self.rng.gen_range(0..response.0.len());
+            // Turning the last tile which isn't green green would look like a win
+            let others_correct = response
+                .0
+                .iter()
+                .enumerate()
+                .all(|(i, &letter)| i == position || letter == LetterResponse::Correct);
+            let choices: Vec<LetterResponse> = [
+                LetterResponse::Correct,
+                LetterResponse::Misplaced,
+                LetterResponse::Absent,
+            ]
+            .into_iter()
+            .filter(|&letter| letter != response.0[position])
+            .filter(|&letter| !(others_correct && letter == LetterResponse::Correct))
+            .collect();
+            response.0[position] = choices[self.rng.gen_range(0..choices.len())];
+            Some(position)
+        };
+        self.history.push((word.to_string(), response.clone()));
+        self.lies.push(lie);
+        Ok(response)
+    }
+
+    /// Like [`FibbleEngine::try_guess`], but returning `None` if the guess is invalid
+    pub fn guess(&mut self, word: &str) -> Option<WordleResponse> {
+        self.try_guess(word).ok()
+    }
+
+    /// The guesses made so far, along with the responses shown, lies included
+    pub fn history(&self) -> &[(String, WordleResponse)] {
+        &self.history
+    }
+
+    /// The position of the lie in each response so far, or `None` for a correct guess. This
+    /// gives the game away, so it's for showing once the game is over, or for testing solvers.
+    pub fn lies(&self) -> &[Option<usize>] {
+        &self.lies
+    }
+
+    /// The game without the lies, whose history has the true responses
+    pub fn engine(&self) -> &WordleEngine {
+        &self.engine
+    }
+
+    pub fn solved(&self) -> bool {
+        self.engine.solved()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.engine.is_over()
+    }
+
+    pub fn get_solution(&self) -> &str {
+        self.engine.get_solution()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WordList;
+
+    #[test]
+    fn test_one_lie_per_response() {
+        let words = WordList::from_words(["crane", "trace", "react", "cater", "caret"], 5).unwrap();
+        let play = |seed| {
+            let mut game = FibbleEngine::new(WordleEngine::with_answer(words.clone(), "trace"))
+                .with_seed(seed);
+            for guess in ["crane", "react", "cater", "caret", "trace"] {
+                game.guess(guess).unwrap();
+            }
+            game
+        };
+        let game = play(7);
+        assert!(game.solved());
+        for ((guess, shown), (lie, (_, truth))) in game
+            .history()
+            .iter()
+            .zip(game.lies().iter().zip(game.engine().history()))
+        {
+            assert_eq!(*truth, WordleEngine::get_response("trace", guess));
+            let differences: Vec<usize> = (0..truth.0.len())
+                .filter(|&i| shown.0[i] != truth.0[i])
+                .collect();
+            match lie {
+                Some(position) => assert_eq!(differences, [*position]),
+                None => assert!(differences.is_empty() && truth.is_correct()),
+            }
+        }
+        assert_eq!(game.history(), play(7).history());
+    }
+
+    #[test]
+    fn test_lies_never_look_like_a_win() {
+        let words = WordList::from_words(["trace", "trade"], 5).unwrap();
+        for seed in 0..50 {
+            let mut game = FibbleEngine::new(WordleEngine::with_answer(words.clone(), "trace"))
+                .with_seed(seed);
+            // One letter off the answer, so a lie about that letter could have made it all green
+            assert!(!game.guess("trade").unwrap().is_correct());
+            assert!(!game.is_over());
+        }
+    }
+}
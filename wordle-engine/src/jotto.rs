@@ -0,0 +1,79 @@
+use crate::{FeedbackModel, WordleEngine};
+
+/// Jotto feedback: the number of letters the guess shares with the answer, wherever they are.
+/// A letter repeated in the guess counts as often as the answer has it.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Jotto;
+
+/// A game of Jotto, started with [`WordleEngine::with_feedback`]
+pub type JottoEngine = WordleEngine<Jotto>;
+
+/// The number of letters the words have in common, counting repeated letters as often as both
+/// words have them
+pub(crate) fn shared_letters(guess: &str, answer: &str) -> usize {
+    if guess.is_ascii() && answer.is_ascii() {
+        // Solvers call this for every pair of words, so avoid allocating for the usual case
+        let mut counts = [0u8; 128];
+        answer.bytes().for_each(|b| counts[b as usize] += 1);
+        return guess
+            .bytes()
+            .filter(|&b| {
+                let count = &mut counts[b as usize];
+                *count > 0 && {
+                    *count -= 1;
+                    true
+                }
+            })
+            .count();
+    }
+    let mut remaining: Vec<char> = answer.chars().collect();
+    guess
+        .chars()
+        .filter(|c| match remaining.iter().position(|a| a == c) {
+            Some(i) => {
+                remaining.swap_remove(i);
+                true
+            }
+            None => false,
+        })
+        .count()
+}
+
+impl FeedbackModel for Jotto {
+    type Pattern = usize;
+
+    fn feedback(&self, guess: &str, answer: &str) -> usize {
+        shared_letters(guess, answer)
+    }
+
+    fn index(&self, pattern: &usize) -> usize {
+        *pattern
+    }
+
+    fn pattern_count(&self, word_length: usize) -> usize {
+        word_length + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WordList;
+
+    #[test]
+    fn test_shared_letters() {
+        assert_eq!(Jotto.feedback("react", "trace"), 5);
+        assert_eq!(Jotto.feedback("geese", "sheep"), 3);
+        assert_eq!(Jotto.feedback("lymph", "crane"), 0);
+
+        let words = WordList::from_words(["crane", "react", "trace", "lymph"], 5).unwrap();
+        let mut game: JottoEngine = WordleEngine::with_answer(words, "trace").with_feedback(Jotto);
+        assert_eq!(game.guess("lymph"), Some(0));
+        // An anagram shares every letter, but only the answer wins
+        assert_eq!(game.guess("crane"), Some(4));
+        assert_eq!(game.guess("react"), Some(5));
+        assert!(!game.solved());
+        assert_eq!(game.guess("trace"), Some(5));
+        assert!(game.solved());
+    }
+}
@@ -1,49 +1,71 @@
 use rand::{self, seq::SliceRandom};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
 
-/// An engine for playing Wordle
-pub struct WordleEngine {
-    word_list: Vec<&'static str>,
-    solution: &'static str,
-    state: [LetterStatus; 5],
-}
+#[cfg(feature = "embedded-lists")]
+pub mod embedded;
+mod feedback;
+mod fibble;
+mod jotto;
+mod mastermind;
+mod nerdle;
+#[cfg(feature = "serde")]
+mod saved;
+mod stats;
+mod word_list;
+mod xordle;
 
-/// The status of a letter
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[allow(unused)]
-enum LetterStatus {
-    /// Exactly known
-    Exact,
-    /// Known that it is present, but not where
-    Present,
-    /// Not known
-    Unknown,
+pub use feedback::{
+    CountOnly, FeedbackModel, GreensOnly, LetterCounts, Naive, ParsePeaksError, Peaks, PeaksEngine,
+    PeaksLetter, PeaksResponse, Standard,
+};
+pub use fibble::FibbleEngine;
+pub use jotto::{Jotto, JottoEngine};
+pub use mastermind::{Mastermind, MastermindEngine, MASTERMIND_MAX_GUESSES};
+pub use nerdle::{Nerdle, NerdleEngine, NERDLE_LENGTH, NERDLE_SYMBOLS};
+#[cfg(feature = "serde")]
+pub use saved::{ResumeError, SavedGame, SAVE_VERSION};
+pub use stats::{Date, GameRecord, StatsError, StatsStore, StatsSummary};
+pub use word_list::{GuessValidator, WordList, WordListError, DEFAULT_WORD_LENGTH};
+pub use xordle::{XordleEngine, XORDLE_MAX_GUESSES};
+
+/// The number of guesses allowed in a standard game
+pub const MAX_GUESSES: usize = 6;
+
+/// An engine for playing Wordle, or any game where guesses get feedback from a [`FeedbackModel`]
+/// and are checked by a [`GuessValidator`]
+pub struct WordleEngine<F: FeedbackModel = Standard, V: GuessValidator = WordList> {
+    validator: V,
+    solution: String,
+    feedback: F,
+    history: Vec<(String, F::Pattern)>,
+    hard_mode: bool,
+    max_guesses: usize,
 }
 
 impl WordleEngine {
     /// Create a new WordleEngine instance from the given list, with a random word
-    pub fn new(word_list: Vec<&'static str>, solution_list: Vec<&'static str>) -> Self {
-        let solution: &'static str = solution_list
+    pub fn new(word_list: WordList, solution_list: &WordList) -> Self {
+        let solution = solution_list
+            .words()
             .choose(&mut rand::thread_rng())
             .expect("Empty word list");
         Self::with_answer(word_list, solution)
     }
 
     /// Create a new WordleEngine instance with the given word list and given solution
-    pub fn with_answer(word_list: Vec<&'static str>, solution: &'static str) -> Self {
-        WordleEngine {
-            word_list,
-            solution,
-            state: [LetterStatus::Unknown; 5],
-        }
+    pub fn with_answer(word_list: WordList, solution: &str) -> Self {
+        Self::with_validator(word_list, solution)
     }
 
     /// Return the match between the guess and the answer
     pub fn get_response(solution: &str, guess: &str) -> WordleResponse {
         if guess == solution {
-            WordleResponse::correct()
+            WordleResponse::correct(solution.len())
         } else {
-            let mut response = [LetterResponse::Absent; 5];
-            let mut taken = [false; 5];
+            let mut response = vec![LetterResponse::Absent; guess.len()];
+            let mut taken = vec![false; solution.len()];
             guess
                 .chars()
                 .zip(solution.chars())
@@ -69,89 +91,434 @@ impl WordleEngine {
             WordleResponse(response)
         }
     }
+}
+
+impl<V: GuessValidator> WordleEngine<Standard, V> {
+    /// Create a new WordleEngine instance with the given solution, accepting the guesses the
+    /// validator allows instead of the words in a list
+    pub fn with_validator(validator: V, solution: &str) -> Self {
+        WordleEngine {
+            validator,
+            solution: solution.to_string(),
+            feedback: Standard,
+            history: Vec::new(),
+            hard_mode: false,
+            max_guesses: MAX_GUESSES,
+        }
+    }
+}
+
+impl<F: FeedbackModel, V: GuessValidator> WordleEngine<F, V> {
+    /// Set whether this game is played in hard mode, where every guess must keep the green
+    /// letters in place and use every yellow letter revealed so far. Only feedback models with a
+    /// [`FeedbackModel::hard_mode_violation`] check restrict guesses in hard mode.
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Set the number of guesses allowed before the game is lost
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// Play this game with a different feedback model, giving the guesses made so far the
+    /// model's feedback instead
+    pub fn with_feedback<G: FeedbackModel>(self, feedback: G) -> WordleEngine<G, V> {
+        let history = self
+            .history
+            .into_iter()
+            .map(|(guess, _)| {
+                let pattern = feedback.feedback(&guess, &self.solution);
+                (guess, pattern)
+            })
+            .collect();
+        WordleEngine {
+            validator: self.validator,
+            solution: self.solution,
+            feedback,
+            history,
+            hard_mode: self.hard_mode,
+            max_guesses: self.max_guesses,
+        }
+    }
+
+    /// The feedback model this game is played with
+    pub fn feedback(&self) -> &F {
+        &self.feedback
+    }
 
     /// Try to make a given guess. Returns:
     ///  - `None` if the guess is invalid
     ///  - `Some(response)` if the guess is valid
-    pub fn guess(&mut self, word: &str) -> Option<WordleResponse> {
-        if word == self.solution {
-            self.state = [LetterStatus::Exact; 5];
-            Some(WordleResponse::correct())
-        } else if self.can_guess(word) {
-            let mut response = [LetterResponse::Absent; 5];
-            let mut taken = [false; 5];
-            word.chars()
-                .zip(self.solution.chars())
-                .enumerate()
-                .for_each(|(i, (wc, sc))| {
-                    if wc == sc {
-                        self.state[i] = LetterStatus::Exact;
-                        response[i] = LetterResponse::Correct;
-                        taken[i] = true;
-                    }
-                });
-            for (word_char, response) in word.chars().zip(response.iter_mut()) {
-                if *response == LetterResponse::Correct {
-                    continue;
-                }
-                for (solution_char, taken) in self.solution.chars().zip(taken.iter_mut()) {
-                    if !*taken && word_char == solution_char {
-                        *taken = true;
-                        *response = LetterResponse::Misplaced;
-                        break;
-                    }
-                }
-            }
-            Some(WordleResponse(response))
-        } else {
-            None
+    pub fn guess(&mut self, word: &str) -> Option<F::Pattern> {
+        self.try_guess(word).ok()
+    }
+
+    /// Like [`WordleEngine::guess`], but saying why an invalid guess can't be made
+    pub fn try_guess(&mut self, word: &str) -> Result<F::Pattern, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        if word != self.solution {
+            self.check_guess(word)?;
         }
+        let response = self.feedback.feedback(word, &self.solution);
+        self.history.push((word.to_string(), response.clone()));
+        Ok(response)
     }
 
     pub fn solved(&self) -> bool {
-        self.state == [LetterStatus::Exact; 5]
+        self.history
+            .iter()
+            .any(|(guess, _)| *guess == self.solution)
     }
 
     pub fn get_solution(&self) -> &str {
-        self.solution
+        &self.solution
     }
 
-    /// Returns true iff the word is legal to guess
-    fn can_guess(&self, word: &str) -> bool {
-        self.word_list.iter().any(|&w| w == word)
+    /// The guesses made so far, along with their responses
+    pub fn history(&self) -> &[(String, F::Pattern)] {
+        &self.history
+    }
+
+    pub fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    /// Returns true once the answer has been guessed or every guess has been used
+    pub fn is_over(&self) -> bool {
+        self.solved() || self.history.len() >= self.max_guesses
+    }
+
+    /// Check that the word is legal to guess
+    fn check_guess(&self, word: &str) -> Result<(), GuessError> {
+        if word.len() != self.solution.len() {
+            return Err(GuessError::WrongLength {
+                expected: self.solution.len(),
+            });
+        }
+        self.validator.check(word)?;
+        if self.hard_mode {
+            for (guess, response) in &self.history {
+                if let Some(violation) = self.feedback.hard_mode_violation(guess, response, word) {
+                    return Err(GuessError::HardMode(violation));
+                }
+            }
+        }
+        Ok(())
     }
 }
 
+impl<F: FeedbackModel<Pattern = WordleResponse>, V: GuessValidator> WordleEngine<F, V> {
+    /// The best response any guess so far has got for the letter, for colouring a keyboard
+    pub fn letter_response(&self, letter: char) -> Option<LetterResponse> {
+        self.history
+            .iter()
+            .flat_map(|(guess, response)| guess.chars().zip(response.0.iter().cloned()))
+            .filter(|&(c, _)| c == letter)
+            .map(|(_, response)| response)
+            .max_by_key(|response| response.digit())
+    }
+
+    /// The result of the game as coloured squares, in the style of the shared results of the
+    /// original game
+    pub fn share(&self) -> String {
+        let score = if self.solved() {
+            self.history.len().to_string()
+        } else {
+            "X".to_string()
+        };
+        let mut share = format!(
+            "Wordle {}/{}{}\n",
+            score,
+            self.max_guesses,
+            if self.hard_mode { "*" } else { "" }
+        );
+        for (_, response) in &self.history {
+            share.push('\n');
+            share.extend(response.0.iter().map(|letter| match letter {
+                LetterResponse::Correct => '🟩',
+                LetterResponse::Misplaced => '🟨',
+                LetterResponse::Absent => '⬛',
+            }));
+        }
+        share
+    }
+}
+
+/// Why a guess couldn't be made
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GuessError {
+    /// The answer has been guessed or every guess has been used
+    GameOver,
+    /// The guess has the wrong number of letters
+    WrongLength { expected: usize },
+    /// The guess isn't in the list of allowed words
+    NotInWordList,
+    /// The guess isn't a correct equation, in Nerdle
+    InvalidEquation,
+    /// The guess isn't a valid code for the game, in Mastermind
+    InvalidCode,
+    /// The guess doesn't use every hint revealed so far, in hard mode
+    HardMode(HardModeViolation),
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::GameOver => write!(f, "The game is over"),
+            GuessError::WrongLength { expected } => {
+                write!(f, "Guesses must have {} letters", expected)
+            }
+            GuessError::NotInWordList => write!(f, "Not in word list"),
+            GuessError::InvalidCode => write!(f, "Not a valid code"),
+            GuessError::InvalidEquation => write!(f, "Not a valid equation"),
+            GuessError::HardMode(violation) => write!(f, "{}", violation),
+        }
+    }
+}
+
+impl Error for GuessError {}
+
+/// A hint revealed by an earlier guess which a guess doesn't use, in hard mode
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct WordleResponse(pub [LetterResponse; 5]);
+pub enum HardModeViolation {
+    /// A green letter wasn't kept at its position, counting from 0
+    MissingGreen { letter: char, position: usize },
+    /// A revealed letter wasn't used as many times as it was revealed
+    MissingLetter { letter: char, count: usize },
+}
+
+impl Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardModeViolation::MissingGreen { letter, position } => write!(
+                f,
+                "Letter {} must be {}",
+                position + 1,
+                letter.to_ascii_uppercase()
+            ),
+            HardModeViolation::MissingLetter { letter, count: 1 } => {
+                write!(f, "Guess must contain {}", letter.to_ascii_uppercase())
+            }
+            HardModeViolation::MissingLetter { letter, count } => write!(
+                f,
+                "Guess must contain {} {} times",
+                letter.to_ascii_uppercase(),
+                count
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WordleResponse(pub Vec<LetterResponse>);
 impl WordleResponse {
-    pub fn correct() -> Self {
-        Self([LetterResponse::Correct; 5])
+    /// The response for a correct guess of a word with the given length
+    pub fn correct(word_length: usize) -> Self {
+        Self(vec![LetterResponse::Correct; word_length])
+    }
+
+    pub fn is_correct(&self) -> bool {
+        self.0.iter().all(|&r| r == LetterResponse::Correct)
+    }
+
+    /// All possible responses for words of the given length, in order from all correct to all
+    /// absent
+    pub fn all_responses(word_length: usize) -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(word_length as u32);
+        (0..count)
+            .rev()
+            .map(move |index| Self::from_index(index, word_length))
+    }
+
+    /// Encode this response as a number in `0..3.pow(len)`, treating each letter as a base-3
+    /// digit with the first letter being most significant.
+    pub fn index(&self) -> usize {
+        self.0.iter().fold(0, |acc, &resp| acc * 3 + resp.digit())
+    }
+
+    /// The inverse of [`WordleResponse::index`]
+    pub fn from_index(mut index: usize, word_length: usize) -> Self {
+        let mut response = vec![LetterResponse::Absent; word_length];
+        for letter in response.iter_mut().rev() {
+            *letter = match index % 3 {
+                0 => LetterResponse::Absent,
+                1 => LetterResponse::Misplaced,
+                _ => LetterResponse::Correct,
+            };
+            index /= 3;
+        }
+        Self(response)
+    }
+
+    /// Returns true iff `word` may be guessed in hard mode after this response was given to
+    /// `guess`: every green letter stays in place, and every revealed letter is used at least as
+    /// many times as it was revealed.
+    pub fn hard_mode_allows(&self, guess: &str, word: &str) -> bool {
+        self.hard_mode_violation(guess, word).is_none()
+    }
+
+    /// The first hint from this response to `guess` which `word` doesn't use, if any, as
+    /// described by [`WordleResponse::hard_mode_allows`]
+    pub fn hard_mode_violation(&self, guess: &str, word: &str) -> Option<HardModeViolation> {
+        for (position, ((gc, wc), r)) in guess.chars().zip(word.chars()).zip(&self.0).enumerate() {
+            if *r == LetterResponse::Correct && gc != wc {
+                return Some(HardModeViolation::MissingGreen {
+                    letter: gc,
+                    position,
+                });
+            }
+        }
+        guess.chars().find_map(|c| {
+            let revealed = guess
+                .chars()
+                .zip(self.0.iter())
+                .filter(|(gc, r)| *gc == c && **r != LetterResponse::Absent)
+                .count();
+            (word.matches(c).count() < revealed).then_some(HardModeViolation::MissingLetter {
+                letter: c,
+                count: revealed,
+            })
+        })
     }
+}
 
-    pub fn all_responses() -> impl Iterator<Item = Self> {
-        LetterResponse::all_responses()
-            .flat_map(|a| LetterResponse::all_responses().map(move |b| (a, b)))
-            .flat_map(|(a, b)| LetterResponse::all_responses().map(move |c| (a, b, c)))
-            .flat_map(|(a, b, c)| LetterResponse::all_responses().map(move |d| (a, b, c, d)))
-            .flat_map(|(a, b, c, d)| LetterResponse::all_responses().map(move |e| (a, b, c, d, e)))
-            .map(|(a, b, c, d, e)| WordleResponse([a, b, c, d, e]))
+impl Display for WordleResponse {
+    /// Formats the response with `!` for correct, `?` for misplaced, and `.` for absent letters
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for letter in &self.0 {
+            write!(
+                f,
+                "{}",
+                match letter {
+                    LetterResponse::Absent => '.',
+                    LetterResponse::Misplaced => '?',
+                    LetterResponse::Correct => '!',
+                }
+            )?;
+        }
+        Ok(())
     }
 }
 
+impl FromStr for WordleResponse {
+    type Err = ParseResponseError;
+
+    /// Parses the format written by `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| match c {
+                '.' => Ok(LetterResponse::Absent),
+                '?' => Ok(LetterResponse::Misplaced),
+                '!' => Ok(LetterResponse::Correct),
+                c => Err(ParseResponseError(c)),
+            })
+            .collect::<Result<_, _>>()
+            .map(WordleResponse)
+    }
+}
+
+/// An error from parsing a [`WordleResponse`], containing the unexpected character
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseResponseError(pub char);
+
+impl Display for ParseResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unexpected {:?} in response, use '!' for correct, '?' for misplaced, '.' for absent",
+            self.0
+        )
+    }
+}
+
+impl Error for ParseResponseError {}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum LetterResponse {
     Correct,
     Misplaced,
     Absent,
 }
 impl LetterResponse {
-    fn all_responses() -> impl Iterator<Item = Self> {
-        [
-            LetterResponse::Correct,
-            LetterResponse::Misplaced,
-            LetterResponse::Absent,
-        ]
-        .into_iter()
+    /// The base-3 digit for this letter used by [`WordleResponse::index`]
+    fn digit(self) -> usize {
+        match self {
+            LetterResponse::Absent => 0,
+            LetterResponse::Misplaced => 1,
+            LetterResponse::Correct => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_index_round_trip() {
+        for (i, response) in WordleResponse::all_responses(5).enumerate() {
+            assert_eq!(response.index(), 242 - i);
+            assert_eq!(WordleResponse::from_index(response.index(), 5), response);
+        }
+        assert_eq!(WordleResponse::correct(5).index(), 242);
+    }
+
+    #[test]
+    fn test_hard_mode() {
+        let words = WordList::from_words(["crane", "crate", "trace", "slate"], 5).unwrap();
+        let mut engine = WordleEngine::with_answer(words, "trace").with_hard_mode(true);
+        assert_eq!(engine.guess("crate").unwrap().to_string(), "?!!?!");
+        assert_eq!(
+            engine.try_guess("slate"),
+            Err(GuessError::HardMode(HardModeViolation::MissingGreen {
+                letter: 'r',
+                position: 1
+            }))
+        );
+        assert_eq!(engine.guess("crane"), None);
+        assert!(engine.guess("trace").unwrap().is_correct());
+        assert!(engine.solved());
+        assert_eq!(engine.try_guess("crate"), Err(GuessError::GameOver));
+        assert_eq!(engine.share(), "Wordle 2/6*\n\n🟨🟩🟩🟨🟩\n🟩🟩🟩🟩🟩");
+    }
+
+    #[test]
+    fn test_guess_limit() {
+        let words = WordList::from_words(["crane", "crate", "trace", "slate"], 5).unwrap();
+        let mut engine = WordleEngine::with_answer(words, "trace").with_max_guesses(2);
+        assert_eq!(
+            engine.try_guess("trac"),
+            Err(GuessError::WrongLength { expected: 5 })
+        );
+        assert_eq!(engine.try_guess("zzzzz"), Err(GuessError::NotInWordList));
+        engine.guess("crane").unwrap();
+        assert_eq!(engine.letter_response('c'), Some(LetterResponse::Misplaced));
+        engine.guess("crate").unwrap();
+        assert_eq!(engine.letter_response('c'), Some(LetterResponse::Misplaced));
+        assert_eq!(engine.letter_response('r'), Some(LetterResponse::Correct));
+        assert!(engine.is_over() && !engine.solved());
+        assert!(engine.share().starts_with("Wordle X/2\n"));
+    }
+
+    #[test]
+    fn test_other_feedback() {
+        let words = WordList::from_words(["crane", "crate", "trace", "slate"], 5).unwrap();
+        let mut engine = WordleEngine::with_answer(words, "trace").with_hard_mode(true);
+        engine.guess("crate").unwrap();
+        let mut engine: PeaksEngine = engine.with_feedback(Peaks);
+        assert_eq!(engine.history()[0].1.to_string(), ">!!<!");
+        // Peaks has no hard mode rules
+        assert_eq!(engine.guess("slate").unwrap().to_string(), ">>!<!");
+        assert!(engine.guess("trace").unwrap().is_correct());
+        assert!(engine.solved());
     }
 }
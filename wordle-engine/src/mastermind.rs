@@ -0,0 +1,175 @@
+use rand::seq::SliceRandom;
+
+use crate::{CountOnly, GuessError, GuessValidator, WordleEngine};
+
+/// The number of guesses allowed in a standard game of Mastermind
+pub const MASTERMIND_MAX_GUESSES: usize = 10;
+
+/// The codes of a game of Mastermind: sequences of a fixed length over a number of colours,
+/// written as letters from `a`. The classic game has codes of 4 pegs over 6 colours, with
+/// repeated colours allowed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Mastermind {
+    length: usize,
+    colours: usize,
+    duplicates: bool,
+}
+
+impl Mastermind {
+    /// Codes of the given length over the given number of colours, allowing repeated colours
+    ///
+    /// # Panics
+    ///
+    /// If there are more than 26 colours, since they're written as letters
+    pub fn new(length: usize, colours: usize) -> Self {
+        assert!(colours <= 26, "At most 26 colours are supported");
+        Mastermind {
+            length,
+            colours,
+            duplicates: true,
+        }
+    }
+
+    /// Set whether a code may use a colour more than once
+    pub fn with_duplicates(mut self, duplicates: bool) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn colours(&self) -> usize {
+        self.colours
+    }
+
+    pub fn duplicates(&self) -> bool {
+        self.duplicates
+    }
+
+    /// Returns whether the code has the right length, uses only the game's colours, and doesn't
+    /// repeat a colour unless that's allowed
+    pub fn is_valid(&self, code: &str) -> bool {
+        code.len() == self.length
+            && code
+                .bytes()
+                .all(|b| (b'a'..b'a' + self.colours as u8).contains(&b))
+            && (self.duplicates
+                || code
+                    .bytes()
+                    .enumerate()
+                    .all(|(i, b)| !code.as_bytes()[..i].contains(&b)))
+    }
+
+    /// Every valid code, in alphabetical order
+    ///
+    /// # Panics
+    ///
+    /// If there are too many codes of the length to count, before leaving out the ones which
+    /// repeat a colour
+    pub fn codes(&self) -> Vec<String> {
+        let total = self
+            .colours
+            .checked_pow(self.length as u32)
+            .expect("Too many codes to list");
+        (0..total)
+            .map(|mut index| {
+                let mut code = vec![b'a'; self.length];
+                for peg in code.iter_mut().rev() {
+                    *peg += (index % self.colours) as u8;
+                    index /= self.colours;
+                }
+                String::from_utf8(code).expect("Codes are ASCII")
+            })
+            .filter(|code| self.is_valid(code))
+            .collect()
+    }
+}
+
+/// A game of Mastermind, where each guess is answered with the number of pegs of the right colour
+/// in the right place, the black pegs, and of the right colour in the wrong place, the white
+/// pegs. These are the `correct` and `misplaced` counts of [`CountOnly`] feedback.
+pub type MastermindEngine = WordleEngine<CountOnly, Mastermind>;
+
+impl GuessValidator for Mastermind {
+    fn check(&self, guess: &str) -> Result<(), GuessError> {
+        if self.is_valid(guess) {
+            Ok(())
+        } else {
+            Err(GuessError::InvalidCode)
+        }
+    }
+}
+
+impl MastermindEngine {
+    /// Start a game with a random secret code, or return `None` if the rules allow no codes
+    pub fn with_random_secret(rules: Mastermind) -> Option<Self> {
+        let codes = rules.codes();
+        let secret = codes.choose(&mut rand::thread_rng())?;
+        Self::with_secret(rules, secret)
+    }
+
+    /// Start a game with the given secret code, or return `None` if it isn't valid
+    pub fn with_secret(rules: Mastermind, secret: &str) -> Option<Self> {
+        rules.is_valid(secret).then(|| {
+            WordleEngine::with_validator(rules, secret)
+                .with_feedback(CountOnly)
+                .with_max_guesses(MASTERMIND_MAX_GUESSES)
+        })
+    }
+
+    pub fn rules(&self) -> &Mastermind {
+        &self.validator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LetterCounts;
+
+    #[test]
+    fn test_codes() {
+        let classic = Mastermind::new(4, 6);
+        assert_eq!(classic.codes().len(), 1296);
+        assert_eq!(classic.with_duplicates(false).codes().len(), 360);
+        assert!(!classic.with_duplicates(false).is_valid("aabb"));
+        assert!(!classic.is_valid("aabg"));
+    }
+
+    #[test]
+    fn test_no_codes() {
+        let rules = Mastermind::new(7, 6).with_duplicates(false);
+        assert!(rules.codes().is_empty());
+        assert!(MastermindEngine::with_random_secret(rules).is_none());
+        assert!(MastermindEngine::with_random_secret(Mastermind::new(4, 6)).is_some());
+    }
+
+    #[test]
+    fn test_pegs() {
+        let mut game = MastermindEngine::with_secret(Mastermind::new(4, 6), "abcc").unwrap();
+        assert_eq!(
+            game.try_guess("abc"),
+            Err(GuessError::WrongLength { expected: 4 })
+        );
+        assert_eq!(game.try_guess("abcz"), Err(GuessError::InvalidCode));
+        assert_eq!(
+            game.guess("ccab"),
+            Some(LetterCounts {
+                correct: 0,
+                misplaced: 4
+            })
+        );
+        assert_eq!(
+            game.guess("accc"),
+            Some(LetterCounts {
+                correct: 3,
+                misplaced: 0
+            })
+        );
+        assert!(!game.solved());
+        game.guess("abcc").unwrap();
+        assert!(game.solved() && game.is_over());
+    }
+}
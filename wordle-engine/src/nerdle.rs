@@ -0,0 +1,202 @@
+use crate::{GuessError, GuessValidator, Standard, WordleEngine};
+
+/// The length of equations in standard Nerdle
+pub const NERDLE_LENGTH: usize = 8;
+
+/// The characters equations are made of
+pub const NERDLE_SYMBOLS: &str = "0123456789+-*/=";
+
+/// The rules for guesses in Nerdle: an equation of a fixed length, like `12+35=47`, whose left
+/// side is an arithmetic expression with at least one operator and whose right side is the
+/// number it evaluates to.
+///
+/// Expressions use `+`, `-`, `*` and `/` with the usual precedence, numbers have no leading
+/// zeros or signs, and the right side must be a whole number which isn't negative. Division may
+/// leave a fraction partway through an expression, as long as the result is whole.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Nerdle {
+    length: usize,
+}
+
+/// A game of Nerdle, started with [`WordleEngine::with_equation`]
+pub type NerdleEngine = WordleEngine<Standard, Nerdle>;
+
+/// A fraction in lowest terms, with a positive denominator
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Ratio(i64, i64);
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Ratio {
+    fn new(numerator: i64, denominator: i64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Ratio(numerator / divisor, denominator / divisor))
+    }
+
+    fn mul(self, other: Ratio) -> Option<Self> {
+        Ratio::new(self.0.checked_mul(other.0)?, self.1.checked_mul(other.1)?)
+    }
+
+    fn div(self, other: Ratio) -> Option<Self> {
+        Ratio::new(self.0.checked_mul(other.1)?, self.1.checked_mul(other.0)?)
+    }
+
+    fn add(self, other: Ratio) -> Option<Self> {
+        Ratio::new(
+            self.0
+                .checked_mul(other.1)?
+                .checked_add(other.0.checked_mul(self.1)?)?,
+            self.1.checked_mul(other.1)?,
+        )
+    }
+}
+
+/// Parse a number without a sign or leading zeros
+fn parse_number(digits: &str) -> Option<i64> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if digits.len() > 1 && digits.starts_with('0') {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+impl Nerdle {
+    /// The rules for equations of the given length
+    pub fn new(length: usize) -> Self {
+        Nerdle { length }
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The whole number an expression like `3*4-2` evaluates to, or `None` if it isn't a
+    /// well-formed expression, divides by zero, or doesn't come out whole
+    pub fn evaluate(expression: &str) -> Option<i64> {
+        let mut total = Ratio(0, 1);
+        let mut term = Ratio(1, 1);
+        // The operator before the next number, and the sign of the term it's in
+        let mut operator = b'*';
+        let mut sign = 1;
+        let mut rest = expression;
+        loop {
+            let end = rest
+                .bytes()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let number = Ratio(parse_number(&rest[..end])?, 1);
+            term = match operator {
+                b'/' => term.div(number)?,
+                _ => term.mul(number)?,
+            };
+            let Some(&next) = rest.as_bytes().get(end) else {
+                break;
+            };
+            if let b'+' | b'-' = next {
+                total = total.add(Ratio(sign * term.0, term.1))?;
+                term = Ratio(1, 1);
+                sign = if next == b'-' { -1 } else { 1 };
+            } else if !matches!(next, b'*' | b'/') {
+                return None;
+            }
+            operator = next;
+            rest = &rest[end + 1..];
+        }
+        let result = total.add(Ratio(sign * term.0, term.1))?;
+        (result.1 == 1).then_some(result.0)
+    }
+
+    /// Returns whether the guess is an equation of the right length which is true
+    pub fn is_valid(&self, equation: &str) -> bool {
+        if equation.len() != self.length || !equation.chars().all(|c| NERDLE_SYMBOLS.contains(c)) {
+            return false;
+        }
+        let Some((left, right)) = equation.split_once('=') else {
+            return false;
+        };
+        left.contains(['+', '-', '*', '/'])
+            && parse_number(right).is_some_and(|right| Nerdle::evaluate(left) == Some(right))
+    }
+}
+
+impl Default for Nerdle {
+    fn default() -> Self {
+        Self::new(NERDLE_LENGTH)
+    }
+}
+
+impl GuessValidator for Nerdle {
+    fn check(&self, guess: &str) -> Result<(), GuessError> {
+        if self.is_valid(guess) {
+            Ok(())
+        } else {
+            Err(GuessError::InvalidEquation)
+        }
+    }
+}
+
+impl NerdleEngine {
+    /// Start a game of Nerdle with the given equation as the answer, or return `None` if it
+    /// isn't a valid equation
+    pub fn with_equation(answer: &str) -> Option<Self> {
+        let rules = Nerdle::new(answer.len());
+        rules
+            .is_valid(answer)
+            .then(|| WordleEngine::with_validator(rules, answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(Nerdle::evaluate("3*4-2"), Some(10));
+        assert_eq!(Nerdle::evaluate("2-3*4+20"), Some(10));
+        assert_eq!(Nerdle::evaluate("7/2*4"), Some(14));
+        assert_eq!(Nerdle::evaluate("7/2"), None);
+        assert_eq!(Nerdle::evaluate("5/0"), None);
+        assert_eq!(Nerdle::evaluate("05+1"), None);
+        assert_eq!(Nerdle::evaluate("5+"), None);
+        assert_eq!(Nerdle::evaluate("-5+6"), None);
+        assert_eq!(Nerdle::evaluate("5**6"), None);
+    }
+
+    #[test]
+    fn test_nerdle_game() {
+        let rules = Nerdle::default();
+        assert!(rules.is_valid("12+35=47"));
+        assert!(!rules.is_valid("12+35=48"));
+        assert!(!rules.is_valid("1+2=3"));
+        assert!(!rules.is_valid("47=12+35"));
+        assert!(!rules.is_valid("5-9+4=00"));
+
+        assert!(NerdleEngine::with_equation("12+35=48").is_none());
+        let mut game = NerdleEngine::with_equation("12+35=47").unwrap();
+        assert_eq!(game.try_guess("12+35=48"), Err(GuessError::InvalidEquation));
+        assert_eq!(
+            game.try_guess("1+2=3"),
+            Err(GuessError::WrongLength { expected: 8 })
+        );
+        assert_eq!(game.guess("53-12=41").unwrap().to_string(), "??.??!!.");
+        assert!(game.guess("12+35=47").unwrap().is_correct());
+        assert!(game.solved());
+
+        let mut game =
+            WordleEngine::with_validator(|guess: &str| rules.is_valid(guess), "12+35=47");
+        assert_eq!(game.try_guess("12+35=48"), Err(GuessError::NotInWordList));
+        assert!(game.guess("53-12=41").is_some());
+    }
+}
@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+
+use crate::{GuessError, WordList, WordleEngine};
+
+/// The version of [`SavedGame`] written by this library. Games saved with a later version can't
+/// be resumed.
+pub const SAVE_VERSION: u32 = 1;
+
+/// A game in a form which can be stored and resumed later, leaving out the word list, which is
+/// given again when resuming. The responses aren't kept, since they follow from the answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub version: u32,
+    pub answer: String,
+    pub hard_mode: bool,
+    pub max_guesses: usize,
+    /// The guesses made so far, in order
+    pub guesses: Vec<String>,
+}
+
+/// Why a saved game couldn't be resumed
+#[derive(Debug)]
+pub enum ResumeError {
+    Io(io::Error),
+    /// The saved game isn't valid JSON, or is missing fields
+    Format(serde_json::Error),
+    /// The game was saved by a later version of this library
+    UnsupportedVersion(u32),
+    /// The saved answer has a character outside `a` to `z`
+    InvalidAnswer(String),
+    /// The saved answer is a different length from the words in the given list
+    WrongAnswerLength {
+        answer: String,
+        expected: usize,
+    },
+    /// The saved game allows no guesses
+    NoGuesses,
+    /// A saved guess can't be made with the given word list
+    InvalidGuess {
+        guess: String,
+        error: GuessError,
+    },
+}
+
+impl Display for ResumeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResumeError::Io(e) => write!(f, "Error reading the saved game: {}", e),
+            ResumeError::Format(e) => write!(f, "Invalid saved game: {}", e),
+            ResumeError::UnsupportedVersion(version) => write!(
+                f,
+                "The game was saved in version {} of the format, but only versions up to {} are \
+                 supported",
+                version, SAVE_VERSION
+            ),
+            ResumeError::InvalidAnswer(answer) => write!(
+                f,
+                "The saved answer {:?} must only use the letters a to z",
+                answer
+            ),
+            ResumeError::WrongAnswerLength { answer, expected } => write!(
+                f,
+                "The saved answer {:?} should have {} letters to match the word list",
+                answer, expected
+            ),
+            ResumeError::NoGuesses => write!(f, "The saved game must allow at least one guess"),
+            ResumeError::InvalidGuess { guess, error } => {
+                write!(f, "The saved guess {:?} is invalid: {}", guess, error)
+            }
+        }
+    }
+}
+
+impl Error for ResumeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ResumeError::Io(e) => Some(e),
+            ResumeError::Format(e) => Some(e),
+            ResumeError::InvalidGuess { error, .. } => Some(error),
+            ResumeError::UnsupportedVersion(_)
+            | ResumeError::InvalidAnswer(_)
+            | ResumeError::WrongAnswerLength { .. }
+            | ResumeError::NoGuesses => None,
+        }
+    }
+}
+
+impl From<io::Error> for ResumeError {
+    fn from(e: io::Error) -> Self {
+        ResumeError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ResumeError {
+    fn from(e: serde_json::Error) -> Self {
+        ResumeError::Format(e)
+    }
+}
+
+impl From<ResumeError> for io::Error {
+    fn from(e: ResumeError) -> Self {
+        match e {
+            ResumeError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+impl WordleEngine {
+    /// This game in a form which can be stored and passed to [`WordleEngine::resume`]
+    pub fn saved(&self) -> SavedGame {
+        SavedGame {
+            version: SAVE_VERSION,
+            answer: self.solution.clone(),
+            hard_mode: self.hard_mode,
+            max_guesses: self.max_guesses,
+            guesses: self
+                .history
+                .iter()
+                .map(|(guess, _)| guess.clone())
+                .collect(),
+        }
+    }
+
+    /// Continue a saved game, replaying its guesses against the given word list. The saved
+    /// answer must be a lowercase word of the list's length, though it needn't be in the list.
+    pub fn resume(word_list: WordList, saved: &SavedGame) -> Result<Self, ResumeError> {
+        if saved.version > SAVE_VERSION {
+            return Err(ResumeError::UnsupportedVersion(saved.version));
+        }
+        if !saved.answer.bytes().all(|b| b.is_ascii_lowercase()) {
+            return Err(ResumeError::InvalidAnswer(saved.answer.clone()));
+        }
+        if saved.answer.len() != word_list.word_length() {
+            return Err(ResumeError::WrongAnswerLength {
+                answer: saved.answer.clone(),
+                expected: word_list.word_length(),
+            });
+        }
+        if saved.max_guesses == 0 {
+            return Err(ResumeError::NoGuesses);
+        }
+        let mut engine = WordleEngine::with_answer(word_list, &saved.answer)
+            .with_hard_mode(saved.hard_mode)
+            .with_max_guesses(saved.max_guesses);
+        for guess in &saved.guesses {
+            engine
+                .try_guess(guess)
+                .map_err(|error| ResumeError::InvalidGuess {
+                    guess: guess.clone(),
+                    error,
+                })?;
+        }
+        Ok(engine)
+    }
+
+    /// Write this game as JSON
+    pub fn save<W: Write>(&self, writer: W) -> io::Result<()> {
+        serde_json::to_writer_pretty(writer, &self.saved())?;
+        Ok(())
+    }
+
+    /// Read a game written by [`WordleEngine::save`], to be played with the given word list
+    pub fn load<R: Read>(reader: R, word_list: WordList) -> Result<Self, ResumeError> {
+        let saved: SavedGame = serde_json::from_reader(reader)?;
+        Self::resume(word_list, &saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_resume() {
+        let words = WordList::from_words(["crane", "trace", "react"], 5).unwrap();
+        let mut engine = WordleEngine::with_answer(words.clone(), "trace").with_hard_mode(true);
+        engine.guess("crane").unwrap();
+        let mut saved = Vec::new();
+        engine.save(&mut saved).unwrap();
+
+        let mut resumed = WordleEngine::load(saved.as_slice(), words.clone()).unwrap();
+        assert_eq!(resumed.history(), engine.history());
+        assert_eq!(resumed.saved(), engine.saved());
+        assert_eq!(resumed.guess("trace"), engine.guess("trace"));
+        assert!(resumed.solved());
+
+        let mut later = engine.saved();
+        later.version = SAVE_VERSION + 1;
+        assert!(matches!(
+            WordleEngine::resume(words.clone(), &later),
+            Err(ResumeError::UnsupportedVersion(_))
+        ));
+        let mut invalid = engine.saved();
+        invalid.guesses = vec!["zzzzz".to_string()];
+        assert!(matches!(
+            WordleEngine::resume(words, &invalid),
+            Err(ResumeError::InvalidGuess { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_saved_games() {
+        let words = WordList::from_words(["crane", "trace", "react"], 5).unwrap();
+        let saved = WordleEngine::with_answer(words.clone(), "trace").saved();
+        let resume = |saved| WordleEngine::resume(words.clone(), &saved);
+
+        let mut uppercase = saved.clone();
+        uppercase.answer = "TRACE".to_string();
+        assert!(matches!(
+            resume(uppercase),
+            Err(ResumeError::InvalidAnswer(_))
+        ));
+        let mut short = saved.clone();
+        short.answer = "tr".to_string();
+        assert!(matches!(
+            resume(short),
+            Err(ResumeError::WrongAnswerLength { expected: 5, .. })
+        ));
+        let mut no_guesses = saved.clone();
+        no_guesses.max_guesses = 0;
+        assert!(matches!(resume(no_guesses), Err(ResumeError::NoGuesses)));
+        assert!(resume(saved).is_ok());
+    }
+}
@@ -0,0 +1,429 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::WordleEngine;
+
+/// The first line of a statistics file
+const HEADER: &str = "date,answer,guesses,won,hard_mode";
+
+/// A calendar date, in UTC
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days_since_epoch((seconds / 86400) as i64)
+    }
+
+    /// The date the given number of days after 1970-01-01, using Howard Hinnant's `civil_from_days`
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u8;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Date { year, month, day }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parses dates written like `2022-01-31`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("{:?} isn't a date written like 2022-01-31", s);
+        let mut parts = s.splitn(3, '-');
+        let mut part = || parts.next().ok_or_else(invalid);
+        let year = part()?.parse().map_err(|_| invalid())?;
+        let month = part()?.parse().map_err(|_| invalid())?;
+        let day = part()?.parse().map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(invalid());
+        }
+        Ok(Date { year, month, day })
+    }
+}
+
+/// A finished game
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GameRecord {
+    pub date: Date,
+    pub answer: String,
+    /// The number of guesses made
+    pub guesses: usize,
+    pub won: bool,
+    pub hard_mode: bool,
+}
+
+impl GameRecord {
+    /// The record of a game played on the given date, if it's over
+    pub fn from_engine(engine: &WordleEngine, date: Date) -> Option<Self> {
+        engine.is_over().then(|| GameRecord {
+            date,
+            answer: engine.get_solution().to_string(),
+            guesses: engine.history().len(),
+            won: engine.solved(),
+            hard_mode: engine.hard_mode(),
+        })
+    }
+}
+
+impl Display for GameRecord {
+    /// Formats the record as a line of a statistics file
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.date, self.answer, self.guesses, self.won, self.hard_mode
+        )
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = String;
+
+    /// Parses a line of a statistics file
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(',').map(str::trim).collect();
+        let [date, answer, guesses, won, hard_mode] = fields[..] else {
+            return Err(format!("Expected 5 fields, found {}", fields.len()));
+        };
+        let flag = |field: &str| {
+            field
+                .parse::<bool>()
+                .map_err(|_| format!("Expected true or false, found {:?}", field))
+        };
+        Ok(GameRecord {
+            date: date.parse()?,
+            answer: answer.to_string(),
+            guesses: guesses
+                .parse()
+                .map_err(|_| format!("Expected a number of guesses, found {:?}", guesses))?,
+            won: flag(won)?,
+            hard_mode: flag(hard_mode)?,
+        })
+    }
+}
+
+/// An error from reading statistics
+#[derive(Debug)]
+pub enum StatsError {
+    Io(io::Error),
+    /// A line couldn't be parsed as a game record
+    InvalidRecord {
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatsError::Io(e) => write!(f, "Error reading statistics: {}", e),
+            StatsError::InvalidRecord { line, message } => {
+                write!(f, "Line {} of the statistics: {}", line, message)
+            }
+        }
+    }
+}
+
+impl Error for StatsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StatsError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for StatsError {
+    fn from(e: io::Error) -> Self {
+        StatsError::Io(e)
+    }
+}
+
+impl From<StatsError> for io::Error {
+    fn from(e: StatsError) -> Self {
+        match e {
+            StatsError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+/// Every finished game, in the order they were played, kept in a file as comma-separated values
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatsStore {
+    records: Vec<GameRecord>,
+}
+
+/// The usual statistics over a list of games
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatsSummary {
+    pub played: usize,
+    pub wins: usize,
+    /// The number of games won in a row, up to the last game
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// The number of games won with each number of guesses
+    pub distribution: BTreeMap<usize, usize>,
+}
+
+impl StatsSummary {
+    pub fn win_percentage(&self) -> usize {
+        (self.wins * 100).checked_div(self.played).unwrap_or(0)
+    }
+}
+
+impl StatsStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the records from a file, or start afresh if it doesn't exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StatsError> {
+        let mut store = Self::new();
+        match File::open(path) {
+            Ok(file) => {
+                store.import(BufReader::new(file))?;
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        Ok(store)
+    }
+
+    /// Write the records to a file, creating its directory if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.export(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Write every record, in the same format as the statistics file
+    pub fn export<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", HEADER)?;
+        for record in &self.records {
+            writeln!(writer, "{}", record)?;
+        }
+        Ok(())
+    }
+
+    /// Add the records written by [`StatsStore::export`] which aren't already in this store,
+    /// keeping the records in date order. Returns the number of records added.
+    ///
+    /// Identical games, such as two wins in one guess with the same answer on the same day, are
+    /// told apart by counting them, so importing the same records twice adds nothing the second
+    /// time.
+    pub fn import<R: BufRead>(&mut self, reader: R) -> Result<usize, StatsError> {
+        // Parse everything first, so a bad line leaves the store unchanged
+        let mut imported = Vec::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line == HEADER {
+                continue;
+            }
+            let record: GameRecord = line.parse().map_err(|message| StatsError::InvalidRecord {
+                line: i + 1,
+                message,
+            })?;
+            imported.push(record);
+        }
+        let mut existing: HashMap<GameRecord, usize> = HashMap::new();
+        for record in &self.records {
+            *existing.entry(record.clone()).or_insert(0) += 1;
+        }
+        let mut added = 0;
+        for record in imported {
+            match existing.get_mut(&record) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => {
+                    self.records.push(record);
+                    added += 1;
+                }
+            }
+        }
+        // A stable sort keeps the order of games played on the same day
+        self.records.sort_by_key(|record| record.date);
+        Ok(added)
+    }
+
+    /// Add a game played today, returning false if it isn't over
+    pub fn record(&mut self, engine: &WordleEngine) -> bool {
+        match GameRecord::from_engine(engine, Date::today()) {
+            Some(record) => {
+                self.records.push(record);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Add a game given up today as a loss, with the guesses made before giving up
+    pub fn record_forfeit(&mut self, engine: &WordleEngine) {
+        self.records.push(GameRecord {
+            date: Date::today(),
+            answer: engine.get_solution().to_string(),
+            guesses: engine.history().len(),
+            won: false,
+            hard_mode: engine.hard_mode(),
+        });
+    }
+
+    pub fn records(&self) -> &[GameRecord] {
+        &self.records
+    }
+
+    pub fn summary(&self) -> StatsSummary {
+        let mut summary = StatsSummary::default();
+        for record in &self.records {
+            summary.played += 1;
+            if record.won {
+                summary.wins += 1;
+                summary.current_streak += 1;
+                summary.max_streak = summary.max_streak.max(summary.current_streak);
+                *summary.distribution.entry(record.guesses).or_insert(0) += 1;
+            } else {
+                summary.current_streak = 0;
+            }
+        }
+        summary
+    }
+}
+
+impl Display for StatsSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Played: {}  Win %: {}  Current streak: {}  Max streak: {}",
+            self.played,
+            self.win_percentage(),
+            self.current_streak,
+            self.max_streak
+        )?;
+        write!(f, "Guess distribution:")?;
+        for (guesses, count) in &self.distribution {
+            write!(f, "\n\t{}: {}", guesses, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WordList;
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(Date::from_days_since_epoch(0).to_string(), "1970-01-01");
+        assert_eq!(
+            Date::from_days_since_epoch(19_000).to_string(),
+            "2022-01-08"
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(11_016).to_string(),
+            "2000-02-29"
+        );
+        assert_eq!(
+            "2022-01-08".parse::<Date>(),
+            Ok(Date::from_days_since_epoch(19_000))
+        );
+    }
+
+    #[test]
+    fn test_record_forfeit() {
+        let words = WordList::from_words(["crane", "trace"], 5).unwrap();
+        let mut store = StatsStore::new();
+        let mut engine = WordleEngine::with_answer(words.clone(), "trace");
+        engine.guess("trace");
+        assert!(store.record(&engine));
+        let mut engine = WordleEngine::with_answer(words, "trace");
+        engine.guess("crane");
+        store.record_forfeit(&engine);
+        let record = store.records().last().unwrap();
+        assert_eq!((record.guesses, record.won), (1, false));
+        let summary = store.summary();
+        assert_eq!(
+            (summary.played, summary.wins, summary.current_streak),
+            (2, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_summary_and_import() {
+        let words = WordList::from_words(["crane", "trace"], 5).unwrap();
+        let mut store = StatsStore::new();
+        for guesses in [
+            &["trace"][..],
+            &["crane"; 6],
+            &["crane", "trace"],
+            &["trace"],
+        ] {
+            let mut engine = WordleEngine::with_answer(words.clone(), "trace");
+            for guess in guesses {
+                engine.guess(guess);
+            }
+            assert!(store.record(&engine));
+        }
+        let summary = store.summary();
+        assert_eq!((summary.played, summary.wins), (4, 3));
+        assert_eq!((summary.current_streak, summary.max_streak), (2, 2));
+        assert_eq!(summary.distribution.get(&1), Some(&2));
+        assert_eq!(summary.win_percentage(), 75);
+
+        let mut exported = Vec::new();
+        store.export(&mut exported).unwrap();
+        let mut imported = StatsStore::new();
+        assert_eq!(imported.import(exported.as_slice()).unwrap(), 4);
+        assert_eq!(imported.import(exported.as_slice()).unwrap(), 0);
+        assert_eq!(imported, store);
+        assert!(matches!(
+            imported.import(&b"2022-01-08,trace,x,true,false"[..]),
+            Err(StatsError::InvalidRecord { line: 1, .. })
+        ));
+
+        // A bad line partway through adds none of the records before it
+        let mut partial = StatsStore::new();
+        let mut bad = exported.clone();
+        bad.extend_from_slice(b"2022-01-08,trace,x,true,false\n");
+        assert!(matches!(
+            partial.import(bad.as_slice()),
+            Err(StatsError::InvalidRecord { line: 6, .. })
+        ));
+        assert!(partial.records().is_empty());
+    }
+}
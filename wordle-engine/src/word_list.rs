@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::GuessError;
+
+/// The length of words in standard Wordle
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+
+/// Decides which guesses a [`WordleEngine`](crate::WordleEngine) accepts. Word lists accept the
+/// words in them, and any function from a guess to whether it's valid can be used instead.
+pub trait GuessValidator {
+    /// Check that the guess may be made, given that it has the same length as the answer
+    fn check(&self, guess: &str) -> Result<(), GuessError>;
+}
+
+impl GuessValidator for WordList {
+    fn check(&self, guess: &str) -> Result<(), GuessError> {
+        if self.contains(guess) {
+            Ok(())
+        } else {
+            Err(GuessError::NotInWordList)
+        }
+    }
+}
+
+impl<P: Fn(&str) -> bool> GuessValidator for P {
+    fn check(&self, guess: &str) -> Result<(), GuessError> {
+        if self(guess) {
+            Ok(())
+        } else {
+            Err(GuessError::NotInWordList)
+        }
+    }
+}
+
+/// A list of words which can be guessed or used as answers.
+///
+/// Every word is lowercase ASCII and of the same length, and each word appears only once. Order
+/// is preserved from the source the list was loaded from.
+#[derive(Clone, Debug)]
+pub struct WordList {
+    words: Vec<String>,
+    lookup: HashSet<String>,
+    word_length: usize,
+}
+
+impl WordList {
+    /// Load a list of five-letter words from the file at the given path
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, WordListError> {
+        Self::from_path_with_length(path, DEFAULT_WORD_LENGTH)
+    }
+
+    /// Load a list of words of the given length from the file at the given path
+    pub fn from_path_with_length(
+        path: impl AsRef<Path>,
+        word_length: usize,
+    ) -> Result<Self, WordListError> {
+        Self::from_reader_with_length(File::open(path)?, word_length)
+    }
+
+    /// Load a list of five-letter words, one per line, from the given reader
+    pub fn from_reader(reader: impl Read) -> Result<Self, WordListError> {
+        Self::from_reader_with_length(reader, DEFAULT_WORD_LENGTH)
+    }
+
+    /// Load a list of words of the given length, one per line, from the given reader.
+    ///
+    /// Blank lines are skipped, and every other line is trimmed and lowercased before being
+    /// checked.
+    pub fn from_reader_with_length(
+        reader: impl Read,
+        word_length: usize,
+    ) -> Result<Self, WordListError> {
+        let mut list = Self::empty(word_length);
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            list.push(&line?, i + 1)?;
+        }
+        Ok(list)
+    }
+
+    /// Build a list from the given words, which are normalized and checked as if they were read
+    /// one per line from a file.
+    pub fn from_words<I, S>(words: I, word_length: usize) -> Result<Self, WordListError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut list = Self::empty(word_length);
+        for (i, word) in words.into_iter().enumerate() {
+            list.push(word.as_ref(), i + 1)?;
+        }
+        Ok(list)
+    }
+
+    fn empty(word_length: usize) -> Self {
+        WordList {
+            words: Vec::new(),
+            lookup: HashSet::new(),
+            word_length,
+        }
+    }
+
+    /// Normalize and validate a word, then add it if it isn't already present
+    fn push(&mut self, word: &str, line: usize) -> Result<(), WordListError> {
+        let word = word.trim().to_lowercase();
+        if word.is_empty() {
+            return Ok(());
+        }
+        if let Some(character) = word.chars().find(|c| !c.is_ascii_lowercase()) {
+            return Err(WordListError::InvalidCharacter {
+                line,
+                word,
+                character,
+            });
+        }
+        if word.len() != self.word_length {
+            return Err(WordListError::WrongLength {
+                line,
+                word,
+                expected: self.word_length,
+            });
+        }
+        if self.lookup.insert(word.clone()) {
+            self.words.push(word);
+        }
+        Ok(())
+    }
+
+    /// Returns true iff the given word is in this list
+    pub fn contains(&self, word: &str) -> bool {
+        self.lookup.contains(word)
+    }
+
+    /// The length of every word in this list
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// All the words in this list, in the order they were loaded
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.words.iter().map(String::as_str)
+    }
+}
+
+/// An error encountered while loading a [`WordList`]
+#[derive(Debug)]
+pub enum WordListError {
+    /// The underlying source couldn't be read
+    Io(io::Error),
+    /// A word didn't have the expected number of letters
+    WrongLength {
+        line: usize,
+        word: String,
+        expected: usize,
+    },
+    /// A word contained something other than the letters a-z
+    InvalidCharacter {
+        line: usize,
+        word: String,
+        character: char,
+    },
+}
+
+impl Display for WordListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordListError::Io(e) => write!(f, "Error reading word list: {}", e),
+            WordListError::WrongLength {
+                line,
+                word,
+                expected,
+            } => write!(
+                f,
+                "Line {}: \"{}\" has {} letters, expected {}",
+                line,
+                word,
+                word.chars().count(),
+                expected
+            ),
+            WordListError::InvalidCharacter {
+                line,
+                word,
+                character,
+            } => write!(
+                f,
+                "Line {}: \"{}\" contains {:?}, which isn't a letter",
+                line, word, character
+            ),
+        }
+    }
+}
+
+impl Error for WordListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WordListError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WordListError {
+    fn from(e: io::Error) -> Self {
+        WordListError::Io(e)
+    }
+}
+
+impl From<WordListError> for io::Error {
+    fn from(e: WordListError) -> Self {
+        match e {
+            WordListError::Io(e) => e,
+            e => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_and_dedup() {
+        let list = WordList::from_reader("Apple\n  squid \n\napple\nWIRES\n".as_bytes()).unwrap();
+        assert_eq!(list.words(), &["apple", "squid", "wires"]);
+        assert!(list.contains("squid"));
+        assert!(!list.contains("Squid"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_words() {
+        assert!(matches!(
+            WordList::from_reader("apple\nbanana\n".as_bytes()),
+            Err(WordListError::WrongLength { line: 2, .. })
+        ));
+        assert!(matches!(
+            WordList::from_words(["it's"], 4),
+            Err(WordListError::InvalidCharacter {
+                line: 1,
+                character: '\'',
+                ..
+            })
+        ));
+    }
+}
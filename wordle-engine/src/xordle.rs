@@ -0,0 +1,172 @@
+use rand::seq::SliceRandom;
+
+use crate::{GuessError, LetterResponse, WordList, WordleEngine, WordleResponse};
+
+/// The number of guesses allowed in a standard game of Xordle
+pub const XORDLE_MAX_GUESSES: usize = 9;
+
+/// A game of Xordle: two hidden answers with no letters in common, where each guess gets one
+/// response combining the colours from both answers. The game is won once both answers have been
+/// guessed.
+pub struct XordleEngine {
+    word_list: WordList,
+    answers: [String; 2],
+    /// Whether each answer has been guessed
+    found: [bool; 2],
+    history: Vec<(String, WordleResponse)>,
+    max_guesses: usize,
+}
+
+impl XordleEngine {
+    /// Start a game with two random answers from the solution list which share no letters
+    pub fn new(word_list: WordList, solution_list: &WordList) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut firsts: Vec<&str> = solution_list.iter().collect();
+        firsts.shuffle(&mut rng);
+        let (first, second) = firsts
+            .iter()
+            .find_map(|first| {
+                let partners: Vec<&str> = solution_list
+                    .iter()
+                    .filter(|second| Self::share_no_letters(first, second))
+                    .collect();
+                partners.choose(&mut rng).map(|second| (*first, *second))
+            })
+            .expect("No two answers without a letter in common");
+        Self::with_answers(word_list, first, second).expect("The answers share no letters")
+    }
+
+    /// The letters in the word, as a bit for each letter from `a`, or `None` if it has a
+    /// character outside `a` to `z`
+    pub fn letters(word: &str) -> Option<u32> {
+        word.bytes().try_fold(0, |mask, b| {
+            b.is_ascii_lowercase().then(|| mask | 1 << (b - b'a'))
+        })
+    }
+
+    /// Returns whether the words could be the answers of a game: they only use the letters `a`
+    /// to `z`, and no letter is in both
+    pub fn share_no_letters(first: &str, second: &str) -> bool {
+        match (Self::letters(first), Self::letters(second)) {
+            (Some(first), Some(second)) => first & second == 0,
+            _ => false,
+        }
+    }
+
+    /// Start a game with the given answers, or return `None` if they have different lengths,
+    /// share a letter, or use a character outside `a` to `z`
+    pub fn with_answers(word_list: WordList, first: &str, second: &str) -> Option<Self> {
+        if first.len() != second.len() || !Self::share_no_letters(first, second) {
+            return None;
+        }
+        Some(XordleEngine {
+            word_list,
+            answers: [first.to_string(), second.to_string()],
+            found: [false; 2],
+            history: Vec::new(),
+            max_guesses: XORDLE_MAX_GUESSES,
+        })
+    }
+
+    /// Set the number of guesses allowed before the game is lost
+    pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// The response to the guess for the two answers: each tile takes the colour it gets from
+    /// whichever answer has its letter. Since the answers share no letters, at most one of them
+    /// colours each tile.
+    pub fn get_response(answers: [&str; 2], guess: &str) -> WordleResponse {
+        let [first, second] = answers.map(|answer| WordleEngine::get_response(answer, guess));
+        WordleResponse(
+            first
+                .0
+                .into_iter()
+                .zip(second.0)
+                .map(|pair| match pair {
+                    (LetterResponse::Absent, letter) | (letter, _) => letter,
+                })
+                .collect(),
+        )
+    }
+
+    /// Try to make a guess, returning the combined response to it
+    pub fn try_guess(&mut self, word: &str) -> Result<WordleResponse, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        if word.len() != self.answers[0].len() {
+            return Err(GuessError::WrongLength {
+                expected: self.answers[0].len(),
+            });
+        }
+        if !self.word_list.contains(word) && !self.answers.iter().any(|answer| answer == word) {
+            return Err(GuessError::NotInWordList);
+        }
+        let response = Self::get_response([&self.answers[0], &self.answers[1]], word);
+        for (found, answer) in self.found.iter_mut().zip(&self.answers) {
+            *found |= answer == word;
+        }
+        self.history.push((word.to_string(), response.clone()));
+        Ok(response)
+    }
+
+    /// Like [`XordleEngine::try_guess`], but returning `None` if the guess is invalid
+    pub fn guess(&mut self, word: &str) -> Option<WordleResponse> {
+        self.try_guess(word).ok()
+    }
+
+    /// Returns true once both answers have been guessed
+    pub fn solved(&self) -> bool {
+        self.found.iter().all(|&found| found)
+    }
+
+    /// Whether each answer has been guessed
+    pub fn found(&self) -> [bool; 2] {
+        self.found
+    }
+
+    pub fn get_solutions(&self) -> [&str; 2] {
+        [&self.answers[0], &self.answers[1]]
+    }
+
+    /// The guesses made so far, along with their responses
+    pub fn history(&self) -> &[(String, WordleResponse)] {
+        &self.history
+    }
+
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    /// Returns true once both answers have been guessed or every guess has been used
+    pub fn is_over(&self) -> bool {
+        self.solved() || self.history.len() >= self.max_guesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combined_responses() {
+        let words = WordList::from_words(["crane", "moist", "trace", "lymph"], 5).unwrap();
+        assert!(XordleEngine::with_answers(words.clone(), "crane", "trace").is_none());
+        assert!(XordleEngine::with_answers(words.clone(), "crane", "moi5t").is_none());
+        assert!(XordleEngine::with_answers(words.clone(), "CRANE", "moist").is_none());
+        assert_eq!(XordleEngine::letters("abe"), Some(0b10011));
+        assert_eq!(XordleEngine::letters("a{"), None);
+        let mut game = XordleEngine::with_answers(words, "crane", "moist").unwrap();
+        // t is misplaced for moist, and r, a, c and e all come from crane
+        assert_eq!(game.guess("trace").unwrap().to_string(), "?!!?!");
+        assert_eq!(game.guess("lymph").unwrap().to_string(), "..?..");
+        assert_eq!(game.guess("moist").unwrap().to_string(), "!!!!!");
+        assert_eq!(game.found(), [false, true]);
+        assert!(!game.is_over());
+        game.guess("crane").unwrap();
+        assert!(game.solved());
+        assert_eq!(game.guess("crane"), None);
+    }
+}
@@ -0,0 +1,247 @@
+use serde::Serialize;
+use std::cmp::Ordering;
+
+use wordle_engine::{Standard, WordleResponse};
+
+use crate::{bin_counts, Contradiction, Information, Strategy};
+
+/// The highest skill or luck score, as on the WordleBot
+const MAX_SCORE: f64 = 99.0;
+
+/// How one guess of a finished game compares with the best guess there was
+#[derive(Clone, Debug, Serialize)]
+pub struct TurnAnalysis<'a> {
+    pub guess: String,
+    pub response: String,
+    /// The number of answers still possible before the guess
+    pub candidates_before: usize,
+    /// The number of answers still possible after the response
+    pub candidates_after: usize,
+    /// The expected bits of information from the guess
+    pub entropy: f64,
+    /// The guess with the most expected information. This and the scores are `None` if no
+    /// answer in the list fits the responses before the guess, since there's nothing to compare
+    /// against.
+    pub best_guess: Option<&'a str>,
+    pub best_entropy: Option<f64>,
+    /// How close the guess came to the best one, from 0 to 99
+    pub skill: Option<u8>,
+    /// How much better the response was than the others the guess could have got, from 0 to 99,
+    /// where 50 is an average response
+    pub luck: Option<u8>,
+}
+
+/// Every guess of a finished game, graded against the best guesses there were
+#[derive(Clone, Debug, Serialize)]
+pub struct GameAnalysis<'a> {
+    pub turns: Vec<TurnAnalysis<'a>>,
+    pub solved: bool,
+    /// The mean skill over every graded turn, or `None` if no turn could be graded
+    pub skill: Option<u8>,
+    /// The mean luck over every graded turn, or `None` if no turn could be graded
+    pub luck: Option<u8>,
+}
+
+impl<'a> GameAnalysis<'a> {
+    /// Replay the guesses and responses, grading each guess against the best one from the guess
+    /// list, or from the guesses allowed in hard mode. Guesses made once no answer in the list
+    /// fits the responses are left ungraded. Returns the index of the first response which
+    /// contradicts the ones before it, if there is one.
+    pub fn new<S: AsRef<str>>(
+        history: &[(S, WordleResponse)],
+        guess_list: &[&'a str],
+        answers: &[&str],
+        hard_mode: bool,
+    ) -> Result<Self, (usize, Contradiction)> {
+        let word_length = history.first().map_or(0, |(guess, _)| guess.as_ref().len());
+        let mut info = Information::with_word_length(word_length);
+        let mut turns = Vec::new();
+        for (i, (guess, response)) in history.iter().enumerate() {
+            let guess = guess.as_ref();
+            let allowed: Vec<&str> = answers
+                .iter()
+                .filter(|word| info.allows(word))
+                .cloned()
+                .collect();
+            let candidates: Vec<&'a str> = if hard_mode {
+                guess_list
+                    .iter()
+                    .filter(|word| info.hard_mode_allows(word))
+                    .cloned()
+                    .collect()
+            } else {
+                guess_list.to_vec()
+            };
+            let entropy = bits(info.evaluate_guess(&allowed, guess));
+            let grade = (!allowed.is_empty()).then(|| {
+                let best_guess = match allowed.as_slice() {
+                    // Any guess gives no information, but only the answer wins
+                    [answer] => candidates
+                        .iter()
+                        .find(|word| *word == answer)
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            info.get_ideal_guess_with(Strategy::Entropy, &allowed, &candidates)
+                        }),
+                    _ => info.get_ideal_guess_with(Strategy::Entropy, &allowed, &candidates),
+                };
+                let best_entropy = bits(info.evaluate_guess(&allowed, best_guess));
+                let skill = if best_entropy > 0.0 {
+                    entropy / best_entropy
+                } else if allowed.contains(&guess) {
+                    1.0
+                } else {
+                    0.0
+                };
+
+                // A correct guess leaves nothing more to find, so it's luckier than any other
+                // response
+                let counts = bin_counts(&Standard, guess, &allowed);
+                let size = |index: usize| {
+                    if WordleResponse::from_index(index, word_length).is_correct() {
+                        0
+                    } else {
+                        counts[index]
+                    }
+                };
+                let actual = size(response.index());
+                let (worse, equal) = (0..counts.len()).fold((0, 0), |(worse, equal), index| {
+                    match size(index).cmp(&actual) {
+                        Ordering::Greater => (worse + counts[index], equal),
+                        Ordering::Equal => (worse, equal + counts[index]),
+                        Ordering::Less => (worse, equal),
+                    }
+                });
+                let luck = (worse as f64 + equal as f64 / 2.0) / allowed.len() as f64;
+                (best_guess, best_entropy, score(skill), score(luck))
+            });
+
+            info.update(guess, response).map_err(|e| (i, e))?;
+            turns.push(TurnAnalysis {
+                guess: guess.to_string(),
+                response: response.to_string(),
+                candidates_before: allowed.len(),
+                candidates_after: allowed.iter().filter(|word| info.allows(word)).count(),
+                entropy,
+                best_guess: grade.map(|(best_guess, ..)| best_guess),
+                best_entropy: grade.map(|(_, best_entropy, ..)| best_entropy),
+                skill: grade.map(|(.., skill, _)| skill),
+                luck: grade.map(|(.., luck)| luck),
+            });
+        }
+        let mean = |scores: Vec<u8>| {
+            (scores.iter().map(|&s| s as usize).sum::<usize>())
+                .checked_div(scores.len())
+                .map(|mean| mean as u8)
+        };
+        Ok(GameAnalysis {
+            solved: history
+                .last()
+                .is_some_and(|(_, response)| response.is_correct()),
+            skill: mean(turns.iter().filter_map(|turn| turn.skill).collect()),
+            luck: mean(turns.iter().filter_map(|turn| turn.luck).collect()),
+            turns,
+        })
+    }
+}
+
+/// Expected information without the sign of the negative zero left by an empty sum, which would
+/// otherwise show up as "-0.00 bits"
+fn bits(entropy: f64) -> f64 {
+    if entropy > 0.0 {
+        entropy
+    } else {
+        0.0
+    }
+}
+
+/// A fraction from 0 to 1 as a score from 0 to 99
+fn score(fraction: f64) -> u8 {
+    (fraction.clamp(0.0, 1.0) * MAX_SCORE).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: &[&str] = &["aegis", "favor", "wired", "weird", "wires", "fired"];
+
+    fn history(answer: &str, guesses: &[&str]) -> Vec<(String, WordleResponse)> {
+        guesses
+            .iter()
+            .map(|guess| {
+                (
+                    guess.to_string(),
+                    wordle_engine::WordleEngine::get_response(answer, guess),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_skill() {
+        let analysis = GameAnalysis::new(
+            &history("fired", &["aegis", "wired", "fired"]),
+            WORDS,
+            WORDS,
+            false,
+        )
+        .unwrap();
+        assert!(analysis.solved);
+        let candidates: Vec<(usize, usize)> = analysis
+            .turns
+            .iter()
+            .map(|turn| (turn.candidates_before, turn.candidates_after))
+            .collect();
+        assert_eq!(candidates, [(6, 2), (2, 1), (1, 1)]);
+        assert!(analysis.turns[0].skill < Some(99));
+        assert_eq!(analysis.turns[1].skill, Some(99));
+        let last = &analysis.turns[2];
+        assert_eq!(last.best_guess, Some("fired"));
+        assert_eq!((last.skill, last.luck), (Some(99), Some(50)));
+    }
+
+    #[test]
+    fn test_luck() {
+        // "aegis" leaves wired and fired together, and tells every other answer apart
+        let luck = |answer| {
+            GameAnalysis::new(&history(answer, &["aegis"]), WORDS, WORDS, false)
+                .unwrap()
+                .turns[0]
+                .luck
+        };
+        assert_eq!(luck("wired"), Some(17));
+        assert_eq!(luck("favor"), Some(58));
+        assert_eq!(luck("aegis"), Some(91));
+    }
+
+    #[test]
+    fn test_answer_outside_list() {
+        // "aegis" leaves only wired of these answers, which "wired" then rules out
+        let answers = &["aegis", "favor", "wired"];
+        let analysis = GameAnalysis::new(
+            &history("fired", &["aegis", "wired", "fired"]),
+            WORDS,
+            answers,
+            false,
+        )
+        .unwrap();
+        assert!(analysis.solved);
+        let turns = &analysis.turns;
+        assert_eq!(turns[1].best_guess, Some("wired"));
+        assert!(turns[0].skill.is_some() && turns[1].skill.is_some());
+        let last = &turns[2];
+        assert_eq!((last.candidates_before, last.candidates_after), (0, 0));
+        assert_eq!(last.best_guess, None);
+        assert_eq!(last.best_entropy, None);
+        assert!(last.entropy.is_sign_positive());
+        assert_eq!((last.skill, last.luck), (None, None));
+        assert_eq!(
+            analysis.skill,
+            Some((turns[0].skill.unwrap() + turns[1].skill.unwrap()) / 2)
+        );
+
+        let ungraded = GameAnalysis::new(&history("fired", &["fired"]), WORDS, &[], false).unwrap();
+        assert_eq!((ungraded.skill, ungraded.luck), (None, None));
+    }
+}
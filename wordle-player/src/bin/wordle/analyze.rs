@@ -0,0 +1,134 @@
+use std::fmt::Display;
+use std::io::{self, Write};
+use wordle_engine::{WordleEngine, WordleResponse};
+
+use ::wordle_player::GameAnalysis;
+
+use crate::report::write_json;
+use crate::{invalid_input, Format, Lists, Options};
+
+/// Read each guess written as GUESS:RESPONSE, or as just GUESS when the answer is known
+fn parse_history(
+    options: &Options,
+    entries: &[String],
+    answer: Option<&str>,
+) -> io::Result<Vec<(String, WordleResponse)>> {
+    if let Some(answer) = answer {
+        options.check_word(answer)?;
+    }
+    entries
+        .iter()
+        .map(|entry| {
+            let (guess, response) = match (entry.split_once(':'), answer) {
+                (Some((guess, response)), _) => (
+                    guess,
+                    response
+                        .parse::<WordleResponse>()
+                        .map_err(|e| invalid_input(e.to_string()))?,
+                ),
+                (None, Some(answer)) => (entry.as_str(), WordleEngine::get_response(answer, entry)),
+                (None, None) => {
+                    return Err(invalid_input(format!(
+                        "Give the response to {}, written like {}:.?..!, or pass --answer",
+                        entry, entry
+                    )))
+                }
+            };
+            options.check_word(guess)?;
+            if response.0.len() != guess.len() {
+                return Err(invalid_input(format!(
+                    "The response to {} must have one character per letter",
+                    guess
+                )));
+            }
+            Ok((guess.to_string(), response))
+        })
+        .collect()
+}
+
+/// A CSV cell which is left empty for an ungraded turn
+fn cell<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |value| value.to_string())
+}
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    entries: &[String],
+    answer: Option<&str>,
+) -> io::Result<()> {
+    let history = parse_history(options, entries, answer)?;
+    let guesses = lists.guesses();
+    let analysis = GameAnalysis::new(&history, &guesses, &lists.answers(), options.hard_mode)
+        .map_err(|(entry, contradiction)| {
+            invalid_input(format!(
+                "The response to guess {} ({}) contradicts the earlier ones: {}",
+                entry + 1,
+                history[entry].0,
+                contradiction
+            ))
+        })?;
+    let mut out = io::stdout().lock();
+    match options.format {
+        Format::Text => {
+            for (i, turn) in analysis.turns.iter().enumerate() {
+                writeln!(out, "Guess {}: {} {}", i + 1, turn.guess, turn.response)?;
+                match (turn.skill, turn.luck, turn.best_guess, turn.best_entropy) {
+                    (Some(skill), Some(luck), Some(best_guess), Some(best_entropy)) => {
+                        writeln!(out, "\tSkill {}/99  Luck {}/99", skill, luck)?;
+                        writeln!(
+                            out,
+                            "\t{:.2} bits, the best guess was {} with {:.2} bits",
+                            turn.entropy, best_guess, best_entropy
+                        )?;
+                    }
+                    _ => writeln!(
+                        out,
+                        "\tNot graded, since no answer in the list fits the responses so far"
+                    )?,
+                }
+                writeln!(
+                    out,
+                    "\tPossible answers: {} → {}",
+                    turn.candidates_before, turn.candidates_after
+                )?;
+            }
+            if analysis.solved {
+                writeln!(out, "Solved in {}", analysis.turns.len())?;
+            } else {
+                writeln!(out, "Not solved")?;
+            }
+            match (analysis.skill, analysis.luck) {
+                (Some(skill), Some(luck)) => {
+                    writeln!(out, "Overall: skill {}/99, luck {}/99", skill, luck)
+                }
+                _ => writeln!(out, "Overall: no guesses could be graded"),
+            }
+        }
+        Format::Json => write_json(out, &analysis),
+        Format::Csv => {
+            writeln!(
+                out,
+                "turn,guess,response,candidates_before,candidates_after,entropy,best_guess,\
+                 best_entropy,skill,luck"
+            )?;
+            for (i, turn) in analysis.turns.iter().enumerate() {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{},{},{}",
+                    i + 1,
+                    turn.guess,
+                    turn.response,
+                    turn.candidates_before,
+                    turn.candidates_after,
+                    turn.entropy,
+                    cell(turn.best_guess),
+                    cell(turn.best_entropy),
+                    cell(turn.skill),
+                    cell(turn.luck)
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, IsTerminal};
+use std::path::Path;
+use wordle_engine::WordleResponse;
+
+use ::wordle_player::{Information, Session};
+
+use crate::{assist_tui, invalid_input, Lists, Options};
+
+/// Prompt for a line of input, returning `None` at the end of input. The line is trimmed but
+/// keeps its case, since file names given to commands may need capitals.
+fn prompt(message: &str) -> io::Result<Option<String>> {
+    println!("{}", message);
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line)? == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(line.trim().to_string()))
+    }
+}
+
+/// Prompt for the response to a guess until a valid one is given, returning `None` at the end of
+/// input
+fn prompt_response(guess: &str) -> io::Result<Option<WordleResponse>> {
+    loop {
+        let response = match prompt(&format!("What was the response to {}?", guess))? {
+            Some(response) => response,
+            None => return Ok(None),
+        };
+        match response.parse::<WordleResponse>() {
+            Ok(response) if response.0.len() == guess.len() => return Ok(Some(response)),
+            Ok(_) => println!("The response must have one character per letter of the guess"),
+            Err(e) => println!("{}", e),
+        }
+    }
+}
+
+/// Print the single-letter changes to the responses which would leave some answers possible
+fn print_fixes(session: &Session, answers: &[&str]) {
+    let fixes = session.fixes(answers);
+    if fixes.is_empty() {
+        println!("No single-letter change to a response fixes this, use :undo or :edit");
+        return;
+    }
+    println!("Changing one letter of a response would fix this:");
+    for fix in fixes {
+        let (guess, response) = &session.history()[fix.entry];
+        println!(
+            "\tGuess {} ({}): {} instead of {}",
+            fix.entry + 1,
+            guess,
+            fix.response,
+            response
+        );
+    }
+}
+
+/// Read a saved session, checking it's for words of the right length
+pub fn restore(options: &Options, path: &Path) -> io::Result<Session> {
+    let session = Session::load(BufReader::new(File::open(path)?))?;
+    if session.base().word_length() != options.word_length {
+        return Err(invalid_input(format!(
+            "The session is for {}-letter words, but --word-length is {}",
+            session.base().word_length(),
+            options.word_length
+        )));
+    }
+    Ok(session)
+}
+
+const HELP: &str = "Enter each guess and its response, or one of these commands:
+    :undo          Remove the last guess
+    :redo          Restore the last guess removed by :undo
+    :edit N        Change guess N or its response
+    :list          List the guesses so far
+    :reset         Remove every guess
+    :save FILE     Save the session to a file
+    :restore FILE  Replace the session with one saved to a file";
+
+/// Check that a pattern given on the command line is for words of the right length
+fn check_pattern(options: &Options, pattern: Option<Information>) -> io::Result<Information> {
+    match pattern {
+        Some(info) if info.word_length() != options.word_length => Err(invalid_input(format!(
+            "The pattern describes {}-letter words, but --word-length is {}",
+            info.word_length(),
+            options.word_length
+        ))),
+        Some(info) => Ok(info),
+        None => Ok(Information::with_word_length(options.word_length)),
+    }
+}
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    top: usize,
+    pattern: Option<Information>,
+    saved: Option<&Path>,
+    plain: bool,
+) -> io::Result<()> {
+    let session = match saved {
+        Some(path) => restore(options, path)?,
+        None => Session::new(check_pattern(options, pattern)?),
+    };
+    if plain || !io::stdin().is_terminal() {
+        run_plain(options, lists, top, session)
+    } else {
+        assist_tui::run(options, lists, top, session, saved)
+    }
+}
+
+/// Assist by prompting for each guess and response on its own line
+fn run_plain(options: &Options, lists: &Lists, top: usize, mut session: Session) -> io::Result<()> {
+    let guess_list = lists.guesses();
+    let answers = lists.answers();
+    let blank = Information::with_word_length(options.word_length).pattern();
+    println!("{}", HELP);
+    loop {
+        let info = match session.information() {
+            Ok(info) => Some(info),
+            Err((entry, contradiction)) => {
+                println!(
+                    "The response to guess {} ({}) contradicts the earlier ones: {}",
+                    entry + 1,
+                    session.history()[entry].0,
+                    contradiction
+                );
+                print_fixes(&session, &answers);
+                None
+            }
+        };
+        let allowed: Vec<&str> = match &info {
+            Some(info) => answers
+                .iter()
+                .filter(|word| info.allows(word))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        match &info {
+            Some(info) if allowed.is_empty() => {
+                println!("No words match information:");
+                println!("{}", info.summary());
+                print_fixes(&session, &answers);
+            }
+            Some(_) if allowed.len() == 1 => {
+                println!("Answer: {}", allowed[0]);
+                break;
+            }
+            Some(info) => {
+                if info.pattern() != blank {
+                    println!("{}", info.summary());
+                }
+                let candidates: Vec<&str> = if options.hard_mode {
+                    guess_list
+                        .iter()
+                        .filter(|word| info.hard_mode_allows(word))
+                        .cloned()
+                        .collect()
+                } else {
+                    guess_list.clone()
+                };
+                println!(
+                    "Top {} guesses: [{}]",
+                    top,
+                    info.top_n_guesses_with(options.strategy, &candidates, &allowed, top)
+                        .into_iter()
+                        .map(|(word, score)| format!("({}, {:.5})", word, score))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                );
+                if allowed.len() > 10 {
+                    println!("{} words remain", allowed.len());
+                } else {
+                    println!("Remaining words: {:?}", allowed);
+                }
+            }
+            None => {}
+        }
+        let input = match prompt("What was your guess?")? {
+            Some(input) => input,
+            None => break,
+        };
+        let (command, argument) = match input.strip_prefix(':') {
+            Some(command) => match command.split_once(' ') {
+                Some((command, argument)) => (command.to_lowercase(), argument.trim()),
+                None => (command.to_lowercase(), ""),
+            },
+            None => {
+                let input = input.to_lowercase();
+                if let Err(e) = options.check_word(&input) {
+                    println!("{}", e);
+                    continue;
+                }
+                let response = match prompt_response(&input)? {
+                    Some(response) => response,
+                    None => break,
+                };
+                if let (Some(info), false) = (&info, allowed.is_empty()) {
+                    println!(
+                        "You guessed {} (+{})",
+                        input,
+                        info.evaluate_guess(&allowed, &input)
+                    );
+                }
+                session.push(input, response);
+                continue;
+            }
+        };
+        match command.as_str() {
+            "undo" => match session.undo() {
+                Some((guess, _)) => println!("Removed {}", guess),
+                None => println!("There are no guesses to undo"),
+            },
+            "redo" => match session.redo() {
+                Some((guess, _)) => println!("Restored {}", guess),
+                None => println!("There are no guesses to redo"),
+            },
+            "edit" => {
+                let entry = match argument.parse::<usize>() {
+                    Ok(number) if (1..=session.history().len()).contains(&number) => number - 1,
+                    _ => {
+                        println!(
+                            "Give the number of a guess from 1 to {}",
+                            session.history().len()
+                        );
+                        continue;
+                    }
+                };
+                let old_guess = session.history()[entry].0.clone();
+                let guess = match prompt(&format!(
+                    "What was guess {}? (leave blank to keep {})",
+                    entry + 1,
+                    old_guess
+                ))? {
+                    Some(guess) if guess.is_empty() => old_guess,
+                    Some(guess) => guess.to_lowercase(),
+                    None => break,
+                };
+                if let Err(e) = options.check_word(&guess) {
+                    println!("{}", e);
+                    continue;
+                }
+                match prompt_response(&guess)? {
+                    Some(response) => {
+                        session.edit(entry, guess, response);
+                    }
+                    None => break,
+                }
+            }
+            "list" => {
+                for (i, (guess, response)) in session.history().iter().enumerate() {
+                    println!("{}. {} {}", i + 1, guess, response);
+                }
+            }
+            "reset" => {
+                session.reset();
+                println!("Removed every guess");
+            }
+            "save" if !argument.is_empty() => {
+                match File::create(argument).and_then(|file| session.save(BufWriter::new(file))) {
+                    Ok(()) => println!("Saved to {}", argument),
+                    Err(e) => println!("Couldn't save to {}: {}", argument, e),
+                }
+            }
+            "restore" if !argument.is_empty() => match restore(options, Path::new(argument)) {
+                Ok(restored) => {
+                    session = restored;
+                    println!("Restored {}", argument);
+                }
+                Err(e) => println!("Couldn't restore {}: {}", argument, e),
+            },
+            _ => println!("{}", HELP),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,485 @@
+use ratatui::crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+    KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::crossterm::execute;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use wordle_engine::{LetterResponse, WordleResponse, MAX_GUESSES};
+
+use ::wordle_player::{Information, InformationSummary, Session};
+
+use crate::{Lists, Options};
+
+/// Where the session is saved if it wasn't restored from a file
+const DEFAULT_SESSION_FILE: &str = "wordle-session.json";
+
+const KEYBOARD: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+const HELP: &str = "Type a guess, then pick each tile's colour with ←/→ and space or a click. \
+    Enter adds the guess. Ctrl-Z undo, Ctrl-Y redo, Ctrl-R reset, Ctrl-S save, Esc quit.";
+
+/// The width of a tile in the grid, including the gap after it
+const TILE_WIDTH: u16 = 4;
+
+/// What the solver makes of the session's current history
+struct Analysis<'a> {
+    /// The information from the history, if it's consistent
+    info: Option<Information>,
+    allowed: Vec<&'a str>,
+    top: Vec<(&'a str, f64)>,
+    /// Explanations of why no answers are left, if there aren't any
+    problems: Vec<String>,
+}
+
+struct App<'a> {
+    options: &'a Options,
+    answers: Vec<&'a str>,
+    guesses: Vec<&'a str>,
+    top: usize,
+    session: Session,
+    save_path: PathBuf,
+    /// The letters typed so far for the next guess
+    guess: String,
+    /// The colour chosen for each typed letter
+    response: Vec<LetterResponse>,
+    /// The tile of the next guess being coloured
+    cursor: usize,
+    analysis: Analysis<'a>,
+    /// A message about the last action
+    status: String,
+    /// Where the grid was last drawn, to find which tile was clicked
+    grid: Rect,
+}
+
+/// The next colour when cycling a tile through grey, yellow and green
+fn next_colour(response: LetterResponse) -> LetterResponse {
+    match response {
+        LetterResponse::Absent => LetterResponse::Misplaced,
+        LetterResponse::Misplaced => LetterResponse::Correct,
+        LetterResponse::Correct => LetterResponse::Absent,
+    }
+}
+
+fn previous_colour(response: LetterResponse) -> LetterResponse {
+    next_colour(next_colour(response))
+}
+
+fn tile_style(response: LetterResponse) -> Style {
+    let style = Style::new().fg(Color::Black).add_modifier(Modifier::BOLD);
+    match response {
+        LetterResponse::Correct => style.bg(Color::Green),
+        LetterResponse::Misplaced => style.bg(Color::Yellow),
+        LetterResponse::Absent => style.fg(Color::White).bg(Color::DarkGray),
+    }
+}
+
+fn tile(letter: char, style: Style) -> [Span<'static>; 2] {
+    [
+        Span::styled(format!(" {} ", letter.to_ascii_uppercase()), style),
+        Span::raw(" "),
+    ]
+}
+
+/// The style for a letter on the keyboard, from what's known about it
+fn key_style(summary: &InformationSummary, letter: char) -> Style {
+    let present = summary.required.contains_key(&letter)
+        || summary.exact_counts.contains_key(&letter)
+        || summary.bounds.get(&letter).is_some_and(|&(min, _)| min > 0);
+    if summary.greens.contains(&Some(letter)) {
+        tile_style(LetterResponse::Correct)
+    } else if present {
+        tile_style(LetterResponse::Misplaced)
+    } else if summary.excluded.contains(&letter) {
+        Style::new().fg(Color::DarkGray)
+    } else {
+        Style::new().add_modifier(Modifier::BOLD)
+    }
+}
+
+impl<'a> App<'a> {
+    fn analyze(&self) -> Analysis<'a> {
+        let info = match self.session.information() {
+            Ok(info) => info,
+            Err((entry, contradiction)) => {
+                let mut problems = vec![format!(
+                    "The response to guess {} ({}) contradicts the earlier ones: {}",
+                    entry + 1,
+                    self.session.history()[entry].0,
+                    contradiction
+                )];
+                problems.extend(self.fix_suggestions());
+                return Analysis {
+                    info: None,
+                    allowed: Vec::new(),
+                    top: Vec::new(),
+                    problems,
+                };
+            }
+        };
+        let allowed: Vec<&'a str> = self
+            .answers
+            .iter()
+            .filter(|word| info.allows(word))
+            .cloned()
+            .collect();
+        if allowed.is_empty() {
+            let mut problems = vec!["No answers match the responses".to_string()];
+            problems.extend(self.fix_suggestions());
+            return Analysis {
+                info: Some(info),
+                allowed,
+                top: Vec::new(),
+                problems,
+            };
+        }
+        let candidates: Vec<&'a str> = if self.options.hard_mode {
+            self.guesses
+                .iter()
+                .filter(|word| info.hard_mode_allows(word))
+                .cloned()
+                .collect()
+        } else {
+            self.guesses.clone()
+        };
+        let top = if allowed.len() == 1 {
+            vec![(allowed[0], 0.0)]
+        } else {
+            info.top_n_guesses_with(self.options.strategy, &candidates, &allowed, self.top)
+        };
+        Analysis {
+            info: Some(info),
+            allowed,
+            top,
+            problems: Vec::new(),
+        }
+    }
+
+    fn fix_suggestions(&self) -> Vec<String> {
+        let fixes = self.session.fixes(&self.answers);
+        if fixes.is_empty() {
+            return vec!["No single tile change fixes this, click a tile or undo".to_string()];
+        }
+        let mut suggestions = vec!["Changing one tile would fix this:".to_string()];
+        for fix in fixes {
+            let (guess, response) = &self.session.history()[fix.entry];
+            suggestions.push(format!(
+                "  Guess {} ({}): {} instead of {}",
+                fix.entry + 1,
+                guess,
+                fix.response,
+                response
+            ));
+        }
+        suggestions
+    }
+
+    /// Redo the analysis after the history changes
+    fn refresh(&mut self) {
+        self.analysis = self.analyze();
+    }
+
+    /// Handle a key press, returning false to quit
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let length = self.options.word_length;
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('c') => return false,
+                KeyCode::Char('z') => {
+                    self.status = match self.session.undo() {
+                        Some((guess, _)) => format!("Removed {}", guess),
+                        None => "There are no guesses to undo".to_string(),
+                    };
+                    self.refresh();
+                }
+                KeyCode::Char('y') => {
+                    self.status = match self.session.redo() {
+                        Some((guess, _)) => format!("Restored {}", guess),
+                        None => "There are no guesses to redo".to_string(),
+                    };
+                    self.refresh();
+                }
+                KeyCode::Char('r') => {
+                    self.session.reset();
+                    self.status = "Removed every guess".to_string();
+                    self.refresh();
+                }
+                KeyCode::Char('s') => {
+                    self.status = match File::create(&self.save_path)
+                        .and_then(|file| self.session.save(BufWriter::new(file)))
+                    {
+                        Ok(()) => format!("Saved to {}", self.save_path.display()),
+                        Err(e) => format!("Couldn't save to {}: {}", self.save_path.display(), e),
+                    };
+                }
+                _ => {}
+            }
+            return true;
+        }
+        match key.code {
+            KeyCode::Esc => return false,
+            KeyCode::Char(c) if c.is_ascii_alphabetic() && self.guess.len() < length => {
+                self.guess.push(c.to_ascii_lowercase());
+                self.response.push(LetterResponse::Absent);
+                self.cursor = self.guess.len() - 1;
+            }
+            KeyCode::Char(' ') | KeyCode::Up if !self.response.is_empty() => {
+                self.response[self.cursor] = next_colour(self.response[self.cursor]);
+            }
+            KeyCode::Down if !self.response.is_empty() => {
+                self.response[self.cursor] = previous_colour(self.response[self.cursor]);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Tab if !self.guess.is_empty() => {
+                self.cursor = (self.cursor + 1) % self.guess.len();
+            }
+            KeyCode::Backspace => {
+                self.guess.pop();
+                self.response.pop();
+                self.cursor = self.guess.len().saturating_sub(1);
+            }
+            KeyCode::Enter => self.add_guess(),
+            _ => {}
+        }
+        true
+    }
+
+    fn add_guess(&mut self) {
+        if let Err(e) = self.options.check_word(&self.guess) {
+            self.status = e.to_string();
+            return;
+        }
+        let guess = std::mem::take(&mut self.guess);
+        let response = WordleResponse(std::mem::take(&mut self.response));
+        self.cursor = 0;
+        self.status = if response.is_correct() {
+            format!("Solved with {}!", guess)
+        } else {
+            format!("Added {} {}", guess, response)
+        };
+        self.session.push(guess, response);
+        self.refresh();
+    }
+
+    /// Cycle the colour of the tile under a click, in an earlier guess or the next one
+    fn handle_click(&mut self, mouse: MouseEvent) {
+        let inner_x = self.grid.x + 1;
+        let inner_y = self.grid.y + 1;
+        if mouse.column < inner_x || mouse.row < inner_y {
+            return;
+        }
+        let offset = mouse.column - inner_x;
+        if offset % TILE_WIDTH == TILE_WIDTH - 1 {
+            return;
+        }
+        let position = (offset / TILE_WIDTH) as usize;
+        let row = (mouse.row - inner_y) as usize;
+        let history = self.session.history();
+        if row < history.len() && position < history[row].1 .0.len() {
+            let (guess, mut response) = history[row].clone();
+            response.0[position] = next_colour(response.0[position]);
+            self.status = format!("Changed guess {} to {} {}", row + 1, guess, response);
+            self.session.edit(row, guess, response);
+            self.refresh();
+        } else if row == history.len() && position < self.response.len() {
+            self.cursor = position;
+            self.response[position] = next_colour(self.response[position]);
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let length = self.options.word_length as u16;
+        let rows = (self.session.history().len() + 1).max(MAX_GUESSES) as u16;
+        let [main, keyboard, status] = Layout::vertical([
+            Constraint::Min(rows + 2),
+            Constraint::Length(5),
+            Constraint::Length(5),
+        ])
+        .areas(frame.area());
+        let [grid, panel] = Layout::horizontal([
+            Constraint::Length(length * TILE_WIDTH + 2),
+            Constraint::Min(20),
+        ])
+        .areas(main);
+        self.grid = grid;
+        frame.render_widget(
+            Paragraph::new(self.grid_lines(rows)).block(Block::bordered().title(" Guesses ")),
+            grid,
+        );
+        self.draw_panel(frame, panel);
+        let summary = self
+            .analysis
+            .info
+            .as_ref()
+            .unwrap_or(self.session.base())
+            .summary();
+        let keys: Vec<Line> = KEYBOARD
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut spans = vec![Span::raw(" ".repeat(i * 2))];
+                spans.extend(row.chars().flat_map(|c| tile(c, key_style(&summary, c))));
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(keys).block(Block::bordered().title(" Letters ")),
+            keyboard,
+        );
+        let mut lines = vec![Line::from(self.status.as_str())];
+        lines.push(Line::styled(HELP, Style::new().fg(Color::DarkGray)));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: true })
+                .block(Block::bordered()),
+            status,
+        );
+    }
+
+    fn grid_lines(&self, rows: u16) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line> = self
+            .session
+            .history()
+            .iter()
+            .map(|(guess, response)| {
+                Line::from(
+                    guess
+                        .chars()
+                        .zip(&response.0)
+                        .flat_map(|(c, &r)| tile(c, tile_style(r)))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        let mut next: Vec<Span> = Vec::new();
+        for (i, c) in self.guess.chars().enumerate() {
+            let mut style = tile_style(self.response[i]);
+            if i == self.cursor {
+                style = style.add_modifier(Modifier::UNDERLINED | Modifier::REVERSED);
+            }
+            next.extend(tile(c, style));
+        }
+        for _ in self.guess.len()..self.options.word_length {
+            next.extend(tile('_', Style::new().fg(Color::DarkGray)));
+        }
+        lines.push(Line::from(next));
+        while lines.len() < rows as usize {
+            lines.push(Line::from(
+                (0..self.options.word_length)
+                    .flat_map(|_| tile('·', Style::new().fg(Color::DarkGray)))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+        lines
+    }
+
+    fn draw_panel(&self, frame: &mut Frame, area: Rect) {
+        if !self.analysis.problems.is_empty() {
+            let lines: Vec<Line> = self
+                .analysis
+                .problems
+                .iter()
+                .map(|problem| Line::from(problem.as_str()))
+                .collect();
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .wrap(Wrap { trim: false })
+                    .block(Block::bordered().title(" Problem ")),
+                area,
+            );
+            return;
+        }
+        let [top, candidates] =
+            Layout::vertical([Constraint::Length(self.top as u16 + 2), Constraint::Min(3)])
+                .areas(area);
+        let lines: Vec<Line> = self
+            .analysis
+            .top
+            .iter()
+            .map(|(word, score)| {
+                let style = if self.analysis.allowed.contains(word) {
+                    Style::new().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::new()
+                };
+                Line::from(vec![
+                    Span::styled(word.to_string(), style),
+                    Span::raw(format!("  {:.3}", score)),
+                ])
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::bordered().title(format!(" Top guesses ({}) ", self.options.strategy)),
+            ),
+            top,
+        );
+        frame.render_widget(
+            Paragraph::new(self.analysis.allowed.join(" "))
+                .wrap(Wrap { trim: true })
+                .block(
+                    Block::bordered()
+                        .title(format!(" Candidates ({}) ", self.analysis.allowed.len())),
+                ),
+            candidates,
+        );
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let keep_going = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key(key),
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    self.handle_click(mouse);
+                    true
+                }
+                _ => true,
+            };
+            if !keep_going {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Assist with an interactive grid where the colour of each tile is picked by keyboard or mouse
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    top: usize,
+    session: Session,
+    saved: Option<&Path>,
+) -> io::Result<()> {
+    let mut app = App {
+        options,
+        answers: lists.answers(),
+        guesses: lists.guesses(),
+        top,
+        session,
+        save_path: saved.map_or_else(|| PathBuf::from(DEFAULT_SESSION_FILE), Path::to_path_buf),
+        guess: String::new(),
+        response: Vec::new(),
+        cursor: 0,
+        analysis: Analysis {
+            info: None,
+            allowed: Vec::new(),
+            top: Vec::new(),
+            problems: Vec::new(),
+        },
+        status: String::new(),
+        grid: Rect::default(),
+    };
+    app.refresh();
+    let mut terminal = ratatui::init();
+    let result = execute!(io::stdout(), EnableMouseCapture).and_then(|_| app.run(&mut terminal));
+    let _ = execute!(io::stdout(), DisableMouseCapture);
+    ratatui::restore();
+    result
+}
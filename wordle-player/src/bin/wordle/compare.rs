@@ -0,0 +1,243 @@
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display};
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use ::wordle_player::{guess_counts, regressions, Regression, Solver, Strategy, Summary};
+
+use crate::report::write_json;
+use crate::{Format, Lists, Options};
+
+/// A strategy to compare, optionally with a fixed opener
+#[derive(Clone, Debug)]
+pub struct Contender {
+    strategy: Strategy,
+    opener: Option<String>,
+}
+
+impl FromStr for Contender {
+    type Err = String;
+
+    /// Parses `STRATEGY` or `STRATEGY:OPENER`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (strategy, opener) = match s.split_once(':') {
+            Some((strategy, opener)) => (strategy, Some(opener.to_lowercase())),
+            None => (s, None),
+        };
+        Ok(Contender {
+            strategy: strategy.parse()?,
+            opener,
+        })
+    }
+}
+
+impl Display for Contender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.opener {
+            Some(opener) => write!(f, "{}:{}", self.strategy, opener),
+            None => write!(f, "{}", self.strategy),
+        }
+    }
+}
+
+/// How one contender performed over the answer list
+#[derive(Serialize)]
+struct Outcome {
+    name: String,
+    strategy: Strategy,
+    opener: String,
+    summary: Summary,
+    #[serde(skip)]
+    counts: BTreeMap<String, usize>,
+}
+
+/// The answers which needed more guesses under `contender` than under `baseline`
+#[derive(Serialize)]
+struct PairRegressions<'a> {
+    baseline: &'a str,
+    contender: &'a str,
+    answers: Vec<Regression>,
+}
+
+pub fn run(options: &Options, lists: &Lists, contenders: &[Contender]) -> io::Result<()> {
+    let default_contenders: Vec<Contender> = Strategy::ALL
+        .into_iter()
+        .map(|strategy| Contender {
+            strategy,
+            opener: None,
+        })
+        .collect();
+    let contenders = if contenders.is_empty() {
+        &default_contenders
+    } else {
+        contenders
+    };
+    for contender in contenders {
+        if let Some(opener) = &contender.opener {
+            options.check_word(opener)?;
+        }
+    }
+    let guesses = lists.guesses();
+    let answers = lists.answers();
+    let outcomes: Vec<Outcome> = contenders
+        .par_iter()
+        .map(|contender| {
+            let solver = Solver::new(&guesses)
+                .with_strategy(contender.strategy)
+                .with_hard_mode(options.hard_mode);
+            let solver = match &contender.opener {
+                Some(opener) => solver.with_opener(opener),
+                None => solver,
+            };
+            let paths = solver.word_paths(answers.clone());
+            Outcome {
+                name: contender.to_string(),
+                strategy: contender.strategy,
+                opener: paths
+                    .first()
+                    .map_or_else(String::new, |(_, path)| path[0].to_string()),
+                summary: Summary::from_paths(&paths),
+                counts: guess_counts(&paths),
+            }
+        })
+        .collect();
+    let mut out = io::stdout().lock();
+    match options.format {
+        Format::Text => write_table(&mut out, &outcomes),
+        Format::Json => {
+            let regressions: Vec<PairRegressions> = outcomes
+                .iter()
+                .flat_map(|baseline| {
+                    outcomes
+                        .iter()
+                        .filter(move |contender| !std::ptr::eq(baseline, *contender))
+                        .map(move |contender| PairRegressions {
+                            baseline: &baseline.name,
+                            contender: &contender.name,
+                            answers: regressions(&baseline.counts, &contender.counts),
+                        })
+                })
+                .collect();
+            write_json(
+                out,
+                &serde_json::json!({
+                    "hard_mode": options.hard_mode,
+                    "contenders": outcomes,
+                    "regressions": regressions,
+                }),
+            )
+        }
+        Format::Csv => {
+            writeln!(
+                out,
+                "answer,{}",
+                outcomes
+                    .iter()
+                    .map(|outcome| outcome.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(",")
+            )?;
+            for answer in &answers {
+                writeln!(
+                    out,
+                    "{},{}",
+                    answer,
+                    outcomes
+                        .iter()
+                        .map(|outcome| outcome.counts[*answer].to_string())
+                        .collect::<Vec<String>>()
+                        .join(",")
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Write the contenders' summaries side by side, followed by the regressions between each pair
+fn write_table(out: &mut impl Write, outcomes: &[Outcome]) -> io::Result<()> {
+    let width = outcomes
+        .iter()
+        .map(|outcome| outcome.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(8);
+    let mut row = |label: &str, values: &mut dyn Iterator<Item = String>| {
+        write!(out, "{:<16}", label)?;
+        for value in values {
+            write!(out, " {:>width$}", value, width = width)?;
+        }
+        writeln!(out)
+    };
+    row("", &mut outcomes.iter().map(|o| o.name.clone()))?;
+    row("Opener", &mut outcomes.iter().map(|o| o.opener.clone()))?;
+    row(
+        "Mean guesses",
+        &mut outcomes.iter().map(|o| format!("{:.4}", o.summary.mean)),
+    )?;
+    let guess_counts: BTreeSet<usize> = outcomes
+        .iter()
+        .flat_map(|o| o.summary.distribution.keys().cloned())
+        .collect();
+    for guesses in guess_counts {
+        row(
+            &format!("{} guesses", guesses),
+            &mut outcomes.iter().map(|o| {
+                o.summary
+                    .distribution
+                    .get(&guesses)
+                    .cloned()
+                    .unwrap_or(0)
+                    .to_string()
+            }),
+        )?;
+    }
+    row(
+        "Failures",
+        &mut outcomes.iter().map(|o| o.summary.failures.to_string()),
+    )?;
+    row(
+        "Worst case",
+        &mut outcomes.iter().map(|o| o.summary.worst_case.to_string()),
+    )?;
+    for (i, baseline) in outcomes.iter().enumerate() {
+        for contender in &outcomes[i + 1..] {
+            let worse = regressions(&baseline.counts, &contender.counts);
+            let better = regressions(&contender.counts, &baseline.counts);
+            writeln!(
+                out,
+                "\n{} vs {}: {} answers need more guesses, {} need fewer",
+                contender.name,
+                baseline.name,
+                worse.len(),
+                better.len()
+            )?;
+            // Both lists show the baseline's guesses first
+            let worse: Vec<(&str, usize, usize)> = worse
+                .iter()
+                .map(|r| (r.answer.as_str(), r.before, r.after))
+                .collect();
+            let better: Vec<(&str, usize, usize)> = better
+                .iter()
+                .map(|r| (r.answer.as_str(), r.after, r.before))
+                .collect();
+            for (label, answers) in [("More", worse), ("Fewer", better)] {
+                if !answers.is_empty() {
+                    writeln!(
+                        out,
+                        "{}: {}",
+                        label,
+                        answers
+                            .iter()
+                            .map(|(answer, from, to)| format!("{} ({} -> {})", answer, from, to))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
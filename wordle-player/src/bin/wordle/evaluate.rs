@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use ::wordle_player::Summary;
+
+use crate::report::{save_summary_csv, Report};
+use crate::{Format, Lists, Options};
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    opener: Option<&str>,
+    summary_csv: Option<&Path>,
+) -> io::Result<()> {
+    let guesses = lists.guesses();
+    let paths = options.solver(&guesses, opener).word_paths(lists.answers());
+    if let Some(path) = summary_csv {
+        save_summary_csv(path, &Summary::from_paths(&paths))?;
+    }
+    match options.format {
+        Format::Text => {
+            let mut bins: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+            for (word, path) in paths {
+                bins.entry(path.len()).or_default().push(word);
+            }
+            let counts: BTreeMap<usize, usize> = bins
+                .iter()
+                .map(|(&count, words)| (count, words.len()))
+                .collect();
+            println!("Counts: {:?}", counts);
+            for (tries, words) in &mut bins {
+                words.sort_unstable();
+                println!("Words that took {} guesses:\n{:?}", tries, words);
+            }
+            Ok(())
+        }
+        Format::Json => Report::new(options, paths).write_json(io::stdout().lock()),
+        Format::Csv => Report::new(options, paths).write_csv(io::stdout().lock()),
+    }
+}
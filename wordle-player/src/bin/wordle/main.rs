@@ -0,0 +1,337 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use wordle_engine::{embedded, WordList, DEFAULT_WORD_LENGTH};
+
+use ::wordle_player::{Information, Solver, Strategy, TreeSearch, MAX_WORD_LENGTH};
+
+mod analyze;
+mod assist;
+mod assist_tui;
+mod cheat_sheet;
+mod compare;
+mod evaluate;
+mod openers;
+mod opening;
+mod play;
+mod report;
+mod serve;
+mod solve;
+mod stats;
+
+/// Play Wordle, or get help solving it
+#[derive(Parser)]
+#[command(name = "wordle")]
+struct Cli {
+    #[command(flatten)]
+    options: Options,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Options shared by every subcommand
+#[derive(Args)]
+struct Options {
+    /// File listing the possible answers, one per line [default: the built-in list]
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+    /// File listing the allowed guesses, one per line [default: the built-in list]
+    #[arg(long, global = true)]
+    guesses: Option<PathBuf>,
+    /// How to score guesses: entropy, minimax, or expected-size
+    #[arg(long, global = true, default_value_t = Strategy::Entropy)]
+    strategy: Strategy,
+    /// Require every guess to use all the hints revealed so far
+    #[arg(long, global = true)]
+    hard_mode: bool,
+    /// The number of letters in each word
+    #[arg(long, global = true, default_value_t = DEFAULT_WORD_LENGTH)]
+    word_length: usize,
+    /// How to format output
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+enum Format {
+    /// Human-readable text
+    Text,
+    /// JSON, for scripts
+    Json,
+    /// Comma-separated values, for spreadsheets and plotting
+    Csv,
+}
+
+/// Options for subcommands which run the solver over many answers
+#[derive(Args)]
+struct SolverOptions {
+    /// Always open with this word instead of the strategy's choice
+    #[arg(long)]
+    opener: Option<String>,
+}
+
+/// Options for subcommands which report the guesses the solver makes for every answer
+#[derive(Args)]
+struct ReportOptions {
+    /// Also write the summary to this file as `metric,value` rows, like `stats --format csv`.
+    /// The CSV report only lists the answers, while the JSON one includes the summary.
+    #[arg(long, value_name = "FILE")]
+    summary_csv: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play a game against a random answer
+    Play {
+        /// Play against this answer instead of a random one
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Suggest guesses for a game being played elsewhere
+    Assist {
+        /// The number of guesses to suggest each turn
+        #[arg(long, default_value_t = 5)]
+        top: usize,
+        /// Start from clues already known, such as "s[^a]a.e +r -bcdo", instead of from nothing
+        #[arg(long)]
+        pattern: Option<Information>,
+        /// Resume a session saved with the :save command
+        #[arg(long, value_name = "FILE", conflicts_with = "pattern")]
+        restore: Option<PathBuf>,
+        /// Prompt for each guess and response on its own line instead of showing the interactive
+        /// grid. This is always done when input isn't from a terminal.
+        #[arg(long)]
+        plain: bool,
+    },
+    /// Print the guesses the solver makes for every answer
+    CheatSheet {
+        #[command(flatten)]
+        solver: SolverOptions,
+        #[command(flatten)]
+        report: ReportOptions,
+    },
+    /// Print how many guesses the solver needs for every answer
+    Evaluate {
+        #[command(flatten)]
+        solver: SolverOptions,
+        #[command(flatten)]
+        report: ReportOptions,
+    },
+    /// Show the guesses the solver makes for the given answers
+    Solve {
+        /// The answers to solve for
+        #[arg(value_name = "ANSWER", required = true)]
+        words: Vec<String>,
+        #[command(flatten)]
+        solver: SolverOptions,
+    },
+    /// Summarize how many guesses the solver needs over the whole answer list
+    Stats {
+        #[command(flatten)]
+        solver: SolverOptions,
+    },
+    /// Compare how several strategies and openers do over the same answers, ignoring --strategy
+    Compare {
+        /// The strategies to compare, each written as STRATEGY or STRATEGY:OPENER [default:
+        /// every strategy]
+        #[arg(value_name = "STRATEGY[:OPENER]")]
+        contenders: Vec<compare::Contender>,
+    },
+    /// Rank the best openers by one-step entropy by building the solver's whole tree under each
+    Openers {
+        /// The number of openers to rank
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Build the optimal tree instead of the greedy one, trying this many of the best guesses
+        /// at each step. This is much slower.
+        #[arg(long, value_name = "BREADTH")]
+        optimal: Option<usize>,
+        /// How to rank the openers
+        #[arg(long, value_enum, default_value_t = openers::RankBy::Mean)]
+        rank_by: openers::RankBy,
+        /// File to save each opener's results to, so an interrupted run can resume where it
+        /// left off
+        #[arg(long, default_value = "wordle-openers.jsonl")]
+        cache: PathBuf,
+        /// Don't read or write the cache file
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Analyze a fixed sequence of opening guesses, played whatever the responses are
+    Opening {
+        /// The guesses, in the order they're played
+        #[arg(value_name = "GUESS")]
+        words: Vec<String>,
+        /// Also list this many of the best words to add to the sequence, under --strategy
+        #[arg(long, value_name = "COUNT")]
+        next: Option<usize>,
+        /// The number of the largest buckets of answers to list
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+    },
+    /// Grade each guess of a finished game by its skill and luck, like the WordleBot
+    Analyze {
+        /// The guesses in order, each with its response unless --answer is given
+        #[arg(value_name = "GUESS[:RESPONSE]", required = true)]
+        entries: Vec<String>,
+        /// The answer of the game, to work out the responses from
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Serve games and suggestions as JSON over HTTP, for other programs to use
+    Serve {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// The port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+}
+
+/// The word lists selected by the options
+struct Lists {
+    answers: WordList,
+    /// Every allowed guess, which includes every answer
+    guesses: WordList,
+}
+
+impl Lists {
+    fn answers(&self) -> Vec<&str> {
+        self.answers.iter().collect()
+    }
+
+    fn guesses(&self) -> Vec<&str> {
+        self.guesses.iter().collect()
+    }
+}
+
+impl Options {
+    fn load_lists(&self) -> io::Result<Lists> {
+        if !(1..=MAX_WORD_LENGTH).contains(&self.word_length) {
+            return Err(invalid_input(format!(
+                "Word length must be between 1 and {}",
+                MAX_WORD_LENGTH
+            )));
+        }
+        let answers = self.load_list(self.answers.as_ref(), embedded::answers())?;
+        let guesses = self.load_list(self.guesses.as_ref(), embedded::guesses())?;
+        let guesses = WordList::from_words(guesses.iter().chain(answers.iter()), self.word_length)?;
+        if answers.is_empty() {
+            return Err(invalid_input("The answer list is empty"));
+        }
+        Ok(Lists { answers, guesses })
+    }
+
+    fn load_list(&self, path: Option<&PathBuf>, default: &WordList) -> io::Result<WordList> {
+        match path {
+            Some(path) => Ok(WordList::from_path_with_length(path, self.word_length)?),
+            None if self.word_length == default.word_length() => Ok(default.clone()),
+            None => Err(invalid_input(format!(
+                "The built-in word lists only have {}-letter words, pass --answers and --guesses",
+                default.word_length()
+            ))),
+        }
+    }
+
+    /// Build the solver selected by these options
+    fn solver<'a>(&self, guesses: &'a [&'a str], opener: Option<&'a str>) -> Solver<'a> {
+        let solver = Solver::new(guesses)
+            .with_strategy(self.strategy)
+            .with_hard_mode(self.hard_mode);
+        match opener {
+            Some(opener) => solver.with_opener(opener),
+            None => solver,
+        }
+    }
+
+    /// Check that a word given on the command line is the right length
+    fn check_word(&self, word: &str) -> io::Result<()> {
+        if word.len() == self.word_length && word.chars().all(|c| c.is_ascii_lowercase()) {
+            Ok(())
+        } else {
+            Err(invalid_input(format!(
+                "{:?} isn't a {}-letter lowercase word",
+                word, self.word_length
+            )))
+        }
+    }
+}
+
+fn invalid_input(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.into())
+}
+
+fn run(Cli { options, command }: Cli) -> io::Result<()> {
+    let lists = options.load_lists()?;
+    if let Command::CheatSheet { solver, .. }
+    | Command::Evaluate { solver, .. }
+    | Command::Solve { solver, .. }
+    | Command::Stats { solver } = &command
+    {
+        if let Some(opener) = &solver.opener {
+            options.check_word(opener)?;
+        }
+    }
+    match command {
+        Command::Play { answer } => play::run(&options, &lists, answer.as_deref()),
+        Command::Assist {
+            top,
+            pattern,
+            restore,
+            plain,
+        } => assist::run(&options, &lists, top, pattern, restore.as_deref(), plain),
+        Command::CheatSheet { solver, report } => cheat_sheet::run(
+            &options,
+            &lists,
+            solver.opener.as_deref(),
+            report.summary_csv.as_deref(),
+        ),
+        Command::Evaluate { solver, report } => evaluate::run(
+            &options,
+            &lists,
+            solver.opener.as_deref(),
+            report.summary_csv.as_deref(),
+        ),
+        Command::Solve { words, solver } => {
+            solve::run(&options, &lists, &words, solver.opener.as_deref())
+        }
+        Command::Stats { solver } => stats::run(&options, &lists, solver.opener.as_deref()),
+        Command::Compare { contenders } => compare::run(&options, &lists, &contenders),
+        Command::Openers {
+            top,
+            optimal,
+            rank_by,
+            cache,
+            no_cache,
+        } => {
+            let search = match optimal {
+                Some(breadth) => TreeSearch::Optimal { breadth },
+                None => TreeSearch::Greedy,
+            };
+            let cache = if no_cache {
+                None
+            } else {
+                Some(cache.as_path())
+            };
+            openers::run(&options, &lists, top, search, rank_by, cache)
+        }
+        Command::Opening {
+            words,
+            next,
+            buckets,
+        } => opening::run(&options, &lists, &words, next, buckets),
+        Command::Analyze { entries, answer } => {
+            analyze::run(&options, &lists, &entries, answer.as_deref())
+        }
+        Command::Serve { host, port } => serve::run(&options, &lists, &host, port),
+    }
+}
+
+fn main() {
+    if let Err(e) = run(Cli::parse()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,215 @@
+use clap::ValueEnum;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use ::wordle_player::{Information, Strategy, Summary, TreeSearch};
+
+use crate::report::write_json;
+use crate::{Format, Lists, Options};
+
+/// How openers are ranked, with ties broken by the other criteria in this order
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum RankBy {
+    /// The mean number of guesses
+    Mean,
+    /// The most guesses needed for any answer
+    WorstCase,
+    /// The fraction of answers needing more than six guesses
+    Failures,
+}
+
+/// What an opener's result depends on, so that cached results are only reused when they apply
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct CacheKey {
+    /// A fingerprint of the answer and guess lists
+    lists: String,
+    strategy: Strategy,
+    hard_mode: bool,
+    search: TreeSearch,
+}
+
+/// The tree built under one opener, which is also one line of the cache file
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct OpenerResult {
+    #[serde(flatten)]
+    key: CacheKey,
+    opener: String,
+    /// The expected bits of information from the opener alone
+    entropy: f64,
+    summary: Summary,
+}
+
+impl OpenerResult {
+    fn failure_rate(&self) -> f64 {
+        self.summary.failures as f64 / self.summary.answers.max(1) as f64
+    }
+}
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    top: usize,
+    search: TreeSearch,
+    rank_by: RankBy,
+    cache: Option<&Path>,
+) -> io::Result<()> {
+    let guesses = lists.guesses();
+    let answers = lists.answers();
+    let key = CacheKey {
+        lists: fingerprint(&answers, &guesses),
+        strategy: options.strategy,
+        hard_mode: options.hard_mode,
+        search,
+    };
+    let openers = Information::with_word_length(options.word_length).top_n_guesses_with(
+        Strategy::Entropy,
+        &guesses,
+        &answers,
+        top,
+    );
+
+    let mut cached: HashMap<String, OpenerResult> = match cache {
+        Some(path) => read_cache(path)?
+            .into_iter()
+            .filter(|result| result.key == key)
+            .map(|result| (result.opener.clone(), result))
+            .collect(),
+        None => HashMap::new(),
+    };
+    let todo: Vec<(&str, f64)> = openers
+        .iter()
+        .filter(|(opener, _)| !cached.contains_key(*opener))
+        .cloned()
+        .collect();
+    if todo.len() < openers.len() {
+        eprintln!("Reusing {} cached openers", openers.len() - todo.len());
+    }
+    let cache_file = match cache {
+        Some(path) => Some(Mutex::new(
+            OpenOptions::new().create(true).append(true).open(path)?,
+        )),
+        None => None,
+    };
+    let progress = Mutex::new(0);
+    let finished: Vec<OpenerResult> = todo
+        .par_iter()
+        .map(|&(opener, entropy)| {
+            let paths = options
+                .solver(&guesses, Some(opener))
+                .with_search(search)
+                .word_paths(answers.clone());
+            let result = OpenerResult {
+                key: key.clone(),
+                opener: opener.to_string(),
+                entropy,
+                summary: Summary::from_paths(&paths),
+            };
+            // Save each result as soon as it's done, so an interrupted run loses as little as
+            // possible.
+            if let Some(file) = &cache_file {
+                let mut file = file.lock().unwrap();
+                serde_json::to_writer(&mut *file, &result)?;
+                writeln!(file)?;
+                file.flush()?;
+            }
+            let mut progress = progress.lock().unwrap();
+            *progress += 1;
+            eprintln!("Evaluated {} ({}/{})", opener, *progress, todo.len());
+            Ok(result)
+        })
+        .collect::<io::Result<_>>()?;
+    for result in finished {
+        cached.insert(result.opener.clone(), result);
+    }
+
+    let mut results: Vec<OpenerResult> = openers
+        .iter()
+        .filter_map(|(opener, _)| cached.remove(*opener))
+        .collect();
+    results.sort_by(|a, b| {
+        let mean = || a.summary.mean.total_cmp(&b.summary.mean);
+        let worst_case = || a.summary.worst_case.cmp(&b.summary.worst_case);
+        let failures = || a.failure_rate().total_cmp(&b.failure_rate());
+        match rank_by {
+            RankBy::Mean => mean().then_with(worst_case).then_with(failures),
+            RankBy::WorstCase => worst_case().then_with(mean).then_with(failures),
+            RankBy::Failures => failures().then_with(mean).then_with(worst_case),
+        }
+    });
+
+    let mut out = io::stdout().lock();
+    match options.format {
+        Format::Text => {
+            writeln!(
+                out,
+                "{:<6}{:<8}{:>9}{:>9}{:>7}{:>10}",
+                "Rank", "Opener", "Entropy", "Mean", "Worst", "Failures"
+            )?;
+            for (i, result) in results.iter().enumerate() {
+                writeln!(
+                    out,
+                    "{:<6}{:<8}{:>9.4}{:>9.4}{:>7}{:>9.2}%",
+                    i + 1,
+                    result.opener,
+                    result.entropy,
+                    result.summary.mean,
+                    result.summary.worst_case,
+                    result.failure_rate() * 100.0
+                )?;
+            }
+            Ok(())
+        }
+        Format::Json => write_json(out, &results),
+        Format::Csv => {
+            writeln!(out, "rank,opener,entropy,mean,worst_case,failure_rate")?;
+            for (i, result) in results.iter().enumerate() {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    i + 1,
+                    result.opener,
+                    result.entropy,
+                    result.summary.mean,
+                    result.summary.worst_case,
+                    result.failure_rate()
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Read every result in the cache file, skipping lines which can't be parsed, such as one
+/// partially written when a run was interrupted.
+fn read_cache(path: &Path) -> io::Result<Vec<OpenerResult>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut results = Vec::new();
+    for line in BufReader::new(file).lines() {
+        if let Ok(result) = serde_json::from_str(&line?) {
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
+
+/// A 64-bit FNV-1a hash of both word lists, which stays the same across builds, unlike the
+/// standard library's hasher.
+fn fingerprint(answers: &[&str], guesses: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in answers.iter().chain([&"|"]).chain(guesses) {
+        for byte in word.bytes().chain([b'\n']) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
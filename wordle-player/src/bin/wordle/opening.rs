@@ -0,0 +1,91 @@
+use serde_json::json;
+use std::io::{self, Write};
+
+use ::wordle_player::{best_next_guesses, OpeningAnalysis};
+
+use crate::report::write_json;
+use crate::{invalid_input, Format, Lists, Options};
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    guesses: &[String],
+    next: Option<usize>,
+    bucket_count: usize,
+) -> io::Result<()> {
+    for guess in guesses {
+        options.check_word(guess)?;
+    }
+    if guesses.is_empty() && next.is_none() {
+        return Err(invalid_input(
+            "Give some opening guesses, or --next to rank openers",
+        ));
+    }
+    let guesses: Vec<&str> = guesses.iter().map(String::as_str).collect();
+    let answers = lists.answers();
+    let analysis = OpeningAnalysis::new(&guesses, &answers);
+    let next = next.map(|count| {
+        best_next_guesses(
+            options.strategy,
+            &guesses,
+            &lists.guesses(),
+            &answers,
+            count,
+        )
+    });
+    let mut out = io::stdout().lock();
+    match options.format {
+        Format::Text => {
+            writeln!(out, "Opening: {}", guesses.join(" "))?;
+            writeln!(out, "Buckets: {}", analysis.buckets.len())?;
+            writeln!(out, "Expected information: {:.4} bits", analysis.entropy)?;
+            writeln!(
+                out,
+                "Answers pinned down uniquely: {} of {} ({:.2}%)",
+                analysis.unique,
+                answers.len(),
+                analysis.unique as f64 * 100.0 / answers.len() as f64
+            )?;
+            writeln!(out, "Bucket sizes:")?;
+            for (size, count) in &analysis.bucket_sizes {
+                writeln!(out, "\t{}: {}", size, count)?;
+            }
+            writeln!(out, "Largest buckets:")?;
+            for bucket in analysis.buckets.iter().take(bucket_count) {
+                writeln!(
+                    out,
+                    "\t{} ({}): {}",
+                    bucket.responses.join(" "),
+                    bucket.answers.len(),
+                    bucket.answers.join(", ")
+                )?;
+            }
+            if let Some(next) = next {
+                writeln!(out, "Best next guesses by {}:", options.strategy)?;
+                for (guess, score) in next {
+                    writeln!(out, "\t{} ({:.5})", guess, score)?;
+                }
+            }
+            Ok(())
+        }
+        Format::Json => write_json(
+            out,
+            &json!({
+                "analysis": analysis,
+                "next": next.map(|next| {
+                    next.into_iter()
+                        .map(|(guess, score)| json!({ "guess": guess, "score": score }))
+                        .collect::<Vec<_>>()
+                }),
+            }),
+        ),
+        Format::Csv => {
+            // Bucket sizes are the part worth plotting; the full partition needs JSON
+            writeln!(out, "bucket_size,buckets")?;
+            for (size, count) in &analysis.bucket_sizes {
+                writeln!(out, "{},{}", size, count)?;
+            }
+            Ok(())
+        }
+    }
+}
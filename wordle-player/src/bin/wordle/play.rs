@@ -0,0 +1,36 @@
+use std::io;
+use wordle_engine::WordleEngine;
+
+use crate::{Lists, Options};
+
+pub fn run(options: &Options, lists: &Lists, answer: Option<&str>) -> io::Result<()> {
+    let engine = match answer {
+        Some(answer) => {
+            options.check_word(answer)?;
+            WordleEngine::with_answer(lists.guesses.clone(), answer)
+        }
+        None => WordleEngine::new(lists.guesses.clone(), &lists.answers),
+    };
+    let mut engine = engine.with_hard_mode(options.hard_mode);
+    let mut guess = String::new();
+    while !engine.is_over() {
+        println!("Please make a guess (leave blank to forfeit):");
+        guess.clear();
+        if io::stdin().read_line(&mut guess)? == 0 || guess.trim().is_empty() {
+            println!("You gave up :(");
+            println!("The answer was {}", engine.get_solution());
+            return Ok(());
+        }
+        match engine.try_guess(guess.trim()) {
+            Err(e) => println!("{}", e),
+            Ok(response) => println!("{}", response),
+        }
+    }
+    if engine.solved() {
+        println!("Solved in {} guesses", engine.history().len());
+    } else {
+        println!("The answer was {}", engine.get_solution());
+    }
+    println!("{}", engine.share());
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use ::wordle_player::{Strategy, Summary};
+
+use crate::Options;
+
+/// The guesses the solver made for every answer, in a form that can be written as JSON or CSV
+#[derive(Serialize)]
+pub struct Report<'a> {
+    pub strategy: Strategy,
+    pub hard_mode: bool,
+    pub opener: Option<&'a str>,
+    pub answers: Vec<AnswerReport<'a>>,
+    pub summary: Summary,
+}
+
+/// The guesses the solver made for one answer
+#[derive(Serialize)]
+pub struct AnswerReport<'a> {
+    pub answer: &'a str,
+    pub guesses: usize,
+    pub path: Vec<&'a str>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(options: &Options, paths: Vec<(&'a str, Vec<&'a str>)>) -> Self {
+        Report {
+            strategy: options.strategy,
+            hard_mode: options.hard_mode,
+            opener: paths.first().map(|(_, path)| path[0]),
+            summary: Summary::from_paths(&paths),
+            answers: paths
+                .into_iter()
+                .map(|(answer, path)| AnswerReport {
+                    answer,
+                    guesses: path.len(),
+                    path,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn write_json(&self, out: impl Write) -> io::Result<()> {
+        write_json(out, self)
+    }
+
+    /// Write one row per answer, with the guesses in the path separated by spaces. The summary
+    /// is left out to keep this a single table, see [`save_summary_csv`].
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "answer,guesses,path")?;
+        for answer in &self.answers {
+            writeln!(
+                out,
+                "{},{},{}",
+                answer.answer,
+                answer.guesses,
+                answer.path.join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Write a value as pretty-printed JSON, followed by a newline
+pub fn write_json(mut out: impl Write, value: &impl Serialize) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut out, value)?;
+    writeln!(out)
+}
+
+/// Write the summary as `metric,value` rows, with one `distribution_<guesses>` row for each
+/// number of guesses.
+pub fn write_summary_csv(mut out: impl Write, summary: &Summary) -> io::Result<()> {
+    writeln!(out, "metric,value")?;
+    writeln!(out, "answers,{}", summary.answers)?;
+    writeln!(out, "mean,{}", summary.mean)?;
+    writeln!(out, "worst_case,{}", summary.worst_case)?;
+    writeln!(out, "failures,{}", summary.failures)?;
+    for (guesses, count) in &summary.distribution {
+        writeln!(out, "distribution_{},{}", guesses, count)?;
+    }
+    Ok(())
+}
+
+/// Write the summary to a file in the form written by [`write_summary_csv`]
+pub fn save_summary_csv(path: &Path, summary: &Summary) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_summary_csv(&mut out, summary)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cli;
+    use clap::Parser;
+
+    fn report(options: &Options) -> Report<'_> {
+        let paths = vec![("trace", vec!["crane", "trace"]), ("crane", vec!["crane"])];
+        Report::new(options, paths)
+    }
+
+    #[test]
+    fn test_write_json() {
+        let options = Cli::parse_from(["wordle", "evaluate", "--strategy", "minimax"]).options;
+        let mut out = Vec::new();
+        report(&options).write_json(&mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json["strategy"], "minimax");
+        assert_eq!(json["opener"], "crane");
+        assert_eq!(
+            json["answers"][0]["path"],
+            serde_json::json!(["crane", "trace"])
+        );
+        assert_eq!(json["summary"]["mean"], 1.5);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let options = Cli::parse_from(["wordle", "evaluate"]).options;
+        let report = report(&options);
+        let mut out = Vec::new();
+        report.write_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "answer,guesses,path\ntrace,2,crane trace\ncrane,1,crane\n"
+        );
+
+        let mut out = Vec::new();
+        write_summary_csv(&mut out, &report.summary).unwrap();
+        let rows: Vec<String> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(rows[..3], ["metric,value", "answers,2", "mean,1.5"]);
+        assert_eq!(
+            rows[rows.len() - 2..],
+            ["distribution_1,1", "distribution_2,1"]
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io;
+use tiny_http::{Header, Method, Response, Server};
+use wordle_engine::{GuessError, WordleEngine, WordleResponse};
+
+use ::wordle_player::{Information, Session};
+
+use crate::{Lists, Options};
+
+/// The number of guesses suggested when a request doesn't say
+const DEFAULT_TOP: usize = 5;
+
+/// A guess and its response, with the response written like `!?..!`
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    guess: String,
+    response: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct NewGame {
+    /// Play against this answer instead of a random one
+    answer: Option<String>,
+    /// Whether to play in hard mode [default: --hard-mode]
+    hard_mode: Option<bool>,
+    max_guesses: Option<usize>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Guess {
+    guess: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SuggestionRequest {
+    history: Vec<Entry>,
+    /// Clues known before the first guess, written as an [`Information`] pattern
+    pattern: Option<String>,
+    top: Option<usize>,
+}
+
+/// The state of a game, leaving out the answer until it's over
+#[derive(Serialize)]
+struct GameStatus<'a> {
+    id: u64,
+    word_length: usize,
+    hard_mode: bool,
+    max_guesses: usize,
+    guesses: Vec<Entry>,
+    solved: bool,
+    over: bool,
+    answer: Option<&'a str>,
+    share: Option<String>,
+}
+
+/// A failed request, sent as `{"error": message}` along with any other details
+struct Failure {
+    status: u16,
+    body: Value,
+}
+
+impl Failure {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Failure {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+
+    fn not_found() -> Self {
+        Self::new(404, "Not found")
+    }
+}
+
+fn entries(history: &[(String, WordleResponse)]) -> Vec<Entry> {
+    history
+        .iter()
+        .map(|(guess, response)| Entry {
+            guess: guess.clone(),
+            response: response.to_string(),
+        })
+        .collect()
+}
+
+fn parse_body<T: DeserializeOwned>(body: &str) -> Result<T, Failure> {
+    let body = if body.trim().is_empty() { "{}" } else { body };
+    serde_json::from_str(body).map_err(|e| Failure::new(400, format!("Invalid request: {}", e)))
+}
+
+/// The games being played, kept in memory until they're deleted or the server stops
+struct Games<'a> {
+    options: &'a Options,
+    lists: &'a Lists,
+    games: HashMap<u64, WordleEngine>,
+    next_id: u64,
+}
+
+impl Games<'_> {
+    /// Answer a request, returning the status code and JSON body
+    fn handle(&mut self, method: &Method, url: &str, body: &str) -> (u16, Value) {
+        match self.route(method, url, body) {
+            Ok((status, body)) => (status, body),
+            Err(failure) => (failure.status, failure.body),
+        }
+    }
+
+    fn route(&mut self, method: &Method, url: &str, body: &str) -> Result<(u16, Value), Failure> {
+        let path = url.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let id = |segment: &str| segment.parse::<u64>().map_err(|_| Failure::not_found());
+        match (method, segments.as_slice()) {
+            (Method::Post, ["games"]) => self.create(parse_body(body)?),
+            (Method::Get, ["games", game]) => {
+                let id = id(game)?;
+                Ok((200, self.status(id)?))
+            }
+            (Method::Delete, ["games", game]) => {
+                let id = id(game)?;
+                self.games.remove(&id).ok_or_else(Failure::not_found)?;
+                Ok((200, json!({ "deleted": id })))
+            }
+            (Method::Post, ["games", game, "guesses"]) => self.guess(id(game)?, parse_body(body)?),
+            (Method::Post, ["suggestions"]) => self.suggest(parse_body(body)?),
+            (_, ["games"] | ["games", _] | ["games", _, "guesses"] | ["suggestions"]) => {
+                Err(Failure::new(405, format!("{} isn't allowed here", method)))
+            }
+            _ => Err(Failure::not_found()),
+        }
+    }
+
+    fn status(&self, id: u64) -> Result<Value, Failure> {
+        let engine = self.games.get(&id).ok_or_else(Failure::not_found)?;
+        let over = engine.is_over();
+        let status = GameStatus {
+            id,
+            word_length: engine.get_solution().len(),
+            hard_mode: engine.hard_mode(),
+            max_guesses: engine.max_guesses(),
+            guesses: entries(engine.history()),
+            solved: engine.solved(),
+            over,
+            answer: over.then(|| engine.get_solution()),
+            share: over.then(|| engine.share()),
+        };
+        Ok(serde_json::to_value(status).expect("A game's status is always valid JSON"))
+    }
+
+    fn create(&mut self, request: NewGame) -> Result<(u16, Value), Failure> {
+        let engine = match &request.answer {
+            Some(answer) => {
+                self.options
+                    .check_word(answer)
+                    .map_err(|e| Failure::new(400, e.to_string()))?;
+                WordleEngine::with_answer(self.lists.guesses.clone(), answer)
+            }
+            None => WordleEngine::new(self.lists.guesses.clone(), &self.lists.answers),
+        };
+        let mut engine = engine.with_hard_mode(request.hard_mode.unwrap_or(self.options.hard_mode));
+        if let Some(max_guesses) = request.max_guesses {
+            if max_guesses == 0 {
+                return Err(Failure::new(400, "max_guesses must be at least 1"));
+            }
+            engine = engine.with_max_guesses(max_guesses);
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.games.insert(id, engine);
+        Ok((201, self.status(id)?))
+    }
+
+    fn guess(&mut self, id: u64, request: Guess) -> Result<(u16, Value), Failure> {
+        let engine = self.games.get_mut(&id).ok_or_else(Failure::not_found)?;
+        let response = engine
+            .try_guess(&request.guess.to_lowercase())
+            .map_err(|e| match e {
+                GuessError::GameOver => Failure::new(409, e.to_string()),
+                e => Failure::new(400, e.to_string()),
+            })?;
+        Ok((
+            200,
+            json!({ "response": response.to_string(), "game": self.status(id)? }),
+        ))
+    }
+
+    fn suggest(&self, request: SuggestionRequest) -> Result<(u16, Value), Failure> {
+        let word_length = self.options.word_length;
+        let base: Information = match &request.pattern {
+            Some(pattern) => pattern
+                .parse()
+                .map_err(|e| Failure::new(400, format!("Invalid pattern: {}", e)))?,
+            None => Information::with_word_length(word_length),
+        };
+        if base.word_length() != word_length {
+            return Err(Failure::new(
+                400,
+                format!("The pattern must describe {}-letter words", word_length),
+            ));
+        }
+        let mut session = Session::new(base);
+        for Entry { guess, response } in request.history {
+            let guess = guess.to_lowercase();
+            self.options
+                .check_word(&guess)
+                .map_err(|e| Failure::new(400, e.to_string()))?;
+            let response: WordleResponse = response
+                .parse()
+                .map_err(|e| Failure::new(400, format!("Invalid response to {}: {}", guess, e)))?;
+            if response.0.len() != word_length {
+                return Err(Failure::new(
+                    400,
+                    format!(
+                        "The response to {} must have {} letters",
+                        guess, word_length
+                    ),
+                ));
+            }
+            session.push(guess, response);
+        }
+
+        let answers = self.lists.answers();
+        let fixes = || -> Vec<Value> {
+            session
+                .fixes(&answers)
+                .into_iter()
+                .map(|fix| {
+                    json!({
+                        "entry": fix.entry,
+                        "position": fix.position,
+                        "response": fix.response.to_string(),
+                    })
+                })
+                .collect()
+        };
+        let info = match session.information() {
+            Ok(info) => info,
+            Err((entry, contradiction)) => {
+                let mut failure = Failure::new(
+                    422,
+                    format!(
+                        "The response to guess {} contradicts the earlier ones: {}",
+                        entry + 1,
+                        contradiction
+                    ),
+                );
+                failure.body["entry"] = json!(entry);
+                failure.body["fixes"] = json!(fixes());
+                return Err(failure);
+            }
+        };
+        let allowed: Vec<&str> = answers
+            .iter()
+            .filter(|word| info.allows(word))
+            .cloned()
+            .collect();
+        let top = request.top.unwrap_or(DEFAULT_TOP);
+        let suggestions: Vec<(&str, f64)> = match allowed.as_slice() {
+            [] => Vec::new(),
+            [answer] => vec![(answer, 0.0)],
+            _ => {
+                let guesses = self.lists.guesses();
+                let candidates: Vec<&str> = if self.options.hard_mode {
+                    guesses
+                        .into_iter()
+                        .filter(|word| info.hard_mode_allows(word))
+                        .collect()
+                } else {
+                    guesses
+                };
+                info.top_n_guesses_with(self.options.strategy, &candidates, &allowed, top)
+            }
+        };
+        Ok((
+            200,
+            json!({
+                "remaining": allowed.len(),
+                "answers": allowed,
+                "suggestions": suggestions
+                    .into_iter()
+                    .map(|(word, score)| json!({ "word": word, "score": score }))
+                    .collect::<Vec<_>>(),
+                "fixes": if allowed.is_empty() { fixes() } else { Vec::new() },
+                "summary": info.summary().to_string(),
+                "pattern": info.pattern(),
+            }),
+        ))
+    }
+}
+
+/// Serve games and suggestions as JSON over HTTP until the process is stopped
+pub fn run(options: &Options, lists: &Lists, host: &str, port: u16) -> io::Result<()> {
+    let server = Server::http((host, port)).map_err(io::Error::other)?;
+    println!("Listening on http://{}", server.server_addr());
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("Content-Type is a valid header");
+    let mut games = Games {
+        options,
+        lists,
+        games: HashMap::new(),
+        next_id: 1,
+    };
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, reply) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => games.handle(request.method(), request.url(), &body),
+            Err(e) => (400, json!({ "error": format!("Invalid request: {}", e) })),
+        };
+        let response = Response::from_string(reply.to_string())
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("Couldn't send a response: {}", e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cli;
+    use clap::Parser;
+    use wordle_engine::WordList;
+
+    fn lists() -> Lists {
+        let words = [
+            "crane", "trace", "react", "cater", "sober", "wired", "fired",
+        ];
+        Lists {
+            answers: WordList::from_words(words, 5).unwrap(),
+            guesses: WordList::from_words(words.iter().chain(&["slate"]), 5).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_games() {
+        let options = Cli::parse_from(["wordle", "serve"]).options;
+        let lists = lists();
+        let mut games = Games {
+            options: &options,
+            lists: &lists,
+            games: HashMap::new(),
+            next_id: 1,
+        };
+
+        let (status, body) = games.handle(&Method::Post, "/games", r#"{"answer": "trace"}"#);
+        assert_eq!(status, 201);
+        assert_eq!(body["id"], 1);
+        assert_eq!(body["answer"], Value::Null);
+
+        let (status, body) =
+            games.handle(&Method::Post, "/games/1/guesses", r#"{"guess": "CRATE"}"#);
+        assert_eq!(status, 400, "{}", body);
+        let (status, body) =
+            games.handle(&Method::Post, "/games/1/guesses", r#"{"guess": "crane"}"#);
+        assert_eq!(status, 200);
+        assert_eq!(body["response"], "?!!.!");
+        let (status, body) =
+            games.handle(&Method::Post, "/games/1/guesses", r#"{"guess": "trace"}"#);
+        assert_eq!(status, 200);
+        assert_eq!(body["game"]["solved"], true);
+        assert_eq!(body["game"]["answer"], "trace");
+        let (status, _) = games.handle(&Method::Post, "/games/1/guesses", r#"{"guess": "trace"}"#);
+        assert_eq!(status, 409);
+
+        let (status, body) = games.handle(&Method::Get, "/games/1", "");
+        assert_eq!(status, 200);
+        assert_eq!(body["guesses"][0]["guess"], "crane");
+        assert_eq!(body["guesses"].as_array().unwrap().len(), 2);
+
+        let (status, body) = games.handle(&Method::Delete, "/games/1", "");
+        assert_eq!((status, body), (200, json!({ "deleted": 1 })));
+        assert_eq!(games.handle(&Method::Get, "/games/1", "").0, 404);
+        assert_eq!(games.handle(&Method::Delete, "/games/1", "").0, 404);
+        assert_eq!(
+            games
+                .handle(&Method::Post, "/games/7/guesses", r#"{"guess": "crane"}"#)
+                .0,
+            404
+        );
+        assert_eq!(games.handle(&Method::Get, "/nowhere", "").0, 404);
+        assert_eq!(games.handle(&Method::Put, "/games/1", "").0, 405);
+        assert_eq!(games.handle(&Method::Get, "/suggestions", "").0, 405);
+        assert_eq!(
+            games
+                .handle(&Method::Post, "/games", r#"{"max_guesses": 0}"#)
+                .0,
+            400
+        );
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let options = Cli::parse_from(["wordle", "serve"]).options;
+        let lists = lists();
+        let mut games = Games {
+            options: &options,
+            lists: &lists,
+            games: HashMap::new(),
+            next_id: 1,
+        };
+
+        let request = r#"{"history": [{"guess": "crane", "response": "?!!.!"}], "top": 2}"#;
+        let (status, body) = games.handle(&Method::Post, "/suggestions", request);
+        assert_eq!(status, 200, "{}", body);
+        assert_eq!(body["remaining"], 1);
+        assert_eq!(body["answers"], json!(["trace"]));
+        assert_eq!(body["suggestions"][0]["word"], "trace");
+        assert_eq!(body["fixes"], json!([]));
+
+        let request = r#"{"history": [
+            {"guess": "crane", "response": "?!!.!"},
+            {"guess": "trace", "response": "!!!!."}
+        ]}"#;
+        let (status, body) = games.handle(&Method::Post, "/suggestions", request);
+        assert_eq!(status, 422, "{}", body);
+        assert_eq!(body["entry"], 1);
+        assert_eq!(
+            body["fixes"],
+            json!([{ "entry": 1, "position": 4, "response": "!!!!!" }])
+        );
+    }
+}
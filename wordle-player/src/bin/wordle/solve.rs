@@ -0,0 +1,97 @@
+use serde::Serialize;
+use std::io::{self, Write};
+
+use crate::report::write_json;
+use crate::{Format, Lists, Options};
+
+/// The guesses the solver made for one answer, with the response to each
+#[derive(Serialize)]
+struct Solution<'a> {
+    answer: &'a str,
+    solved: bool,
+    guesses: Vec<Step<'a>>,
+}
+
+#[derive(Serialize)]
+struct Step<'a> {
+    guess: &'a str,
+    response: String,
+}
+
+pub fn run(
+    options: &Options,
+    lists: &Lists,
+    answers: &[String],
+    opener: Option<&str>,
+) -> io::Result<()> {
+    for answer in answers {
+        options.check_word(answer)?;
+    }
+    let guesses = lists.guesses();
+    let possible = lists.answers();
+    let solver = options.solver(&guesses, opener);
+    let solutions: Vec<Solution> = answers
+        .iter()
+        .map(|answer| {
+            let path = solver.solve(&possible, answer);
+            Solution {
+                answer,
+                solved: path
+                    .last()
+                    .is_some_and(|(_, response)| response.is_correct()),
+                guesses: path
+                    .into_iter()
+                    .map(|(guess, response)| Step {
+                        guess,
+                        response: response.to_string(),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    let mut out = io::stdout().lock();
+    match options.format {
+        Format::Text => {
+            for solution in &solutions {
+                writeln!(
+                    out,
+                    "{}: {}{}",
+                    solution.answer,
+                    solution
+                        .guesses
+                        .iter()
+                        .map(|step| format!("{} {}", step.guess, step.response))
+                        .collect::<Vec<String>>()
+                        .join(" -> "),
+                    if solution.solved {
+                        ""
+                    } else {
+                        " (not in the answer list)"
+                    }
+                )?;
+            }
+            Ok(())
+        }
+        Format::Json => write_json(out, &solutions),
+        Format::Csv => {
+            writeln!(out, "answer,solved,guesses,path,responses")?;
+            for solution in &solutions {
+                let (path, responses): (Vec<&str>, Vec<&str>) = solution
+                    .guesses
+                    .iter()
+                    .map(|step| (step.guess, step.response.as_str()))
+                    .unzip();
+                writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    solution.answer,
+                    solution.solved,
+                    path.len(),
+                    path.join(" "),
+                    responses.join(" ")
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
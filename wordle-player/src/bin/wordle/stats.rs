@@ -0,0 +1,40 @@
+use serde_json::json;
+use std::io;
+
+use ::wordle_player::Summary;
+
+use crate::report::{write_json, write_summary_csv};
+use crate::{Format, Lists, Options};
+
+pub fn run(options: &Options, lists: &Lists, opener: Option<&str>) -> io::Result<()> {
+    let guesses = lists.guesses();
+    let paths = options.solver(&guesses, opener).word_paths(lists.answers());
+    let summary = Summary::from_paths(&paths);
+    let opener = paths.first().map_or("", |(_, path)| path[0]);
+    match options.format {
+        Format::Text => {
+            println!(
+                "Strategy: {}{}",
+                options.strategy,
+                if options.hard_mode {
+                    " (hard mode)"
+                } else {
+                    ""
+                }
+            );
+            println!("Opener: {}", opener);
+            println!("{}", summary);
+            Ok(())
+        }
+        Format::Json => write_json(
+            io::stdout().lock(),
+            &json!({
+                "strategy": options.strategy,
+                "hard_mode": options.hard_mode,
+                "opener": opener,
+                "summary": summary,
+            }),
+        ),
+        Format::Csv => write_summary_csv(io::stdout().lock(), &summary),
+    }
+}
@@ -0,0 +1,189 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use wordle_engine::{LetterResponse, WordleResponse};
+
+use crate::Information;
+
+/// A reason no word could satisfy some information
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Contradiction {
+    /// The constraints on a letter leave no possible number of times for it to appear
+    Count { letter: char, min: u8, max: u8 },
+    /// Every letter has been ruled out at a position, counting from 0
+    NoLetters { position: usize },
+    /// A letter must appear more times than there are open positions which allow it
+    NoRoom { letter: char },
+    /// The letters which must appear don't fit in the word
+    TooManyLetters { required: usize, length: usize },
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Contradiction::Count { letter, min, max } => write!(
+                f,
+                "{} must appear at least {} times but at most {}",
+                letter, min, max
+            ),
+            Contradiction::NoLetters { position } => {
+                write!(f, "Every letter is ruled out at position {}", position + 1)
+            }
+            Contradiction::NoRoom { letter } => write!(
+                f,
+                "{} must appear more times than there are positions which allow it",
+                letter
+            ),
+            Contradiction::TooManyLetters { required, length } => write!(
+                f,
+                "{} letters must appear, but the word only has {}",
+                required, length
+            ),
+        }
+    }
+}
+
+impl Error for Contradiction {}
+
+impl Information {
+    /// Check that some word, not necessarily in any word list, could satisfy this information
+    pub fn check(&self) -> Result<(), Contradiction> {
+        let is_known = |slot: &[Option<bool>; 26]| slot.contains(&Some(true));
+        if let Some(position) = self
+            .exact
+            .iter()
+            .position(|slot| slot.iter().all(|&e| e == Some(false)))
+        {
+            return Err(Contradiction::NoLetters { position });
+        }
+        for (i, &(min, max)) in self.counts.iter().enumerate() {
+            let letter = (i as u8 + 97) as char;
+            if min > max {
+                return Err(Contradiction::Count { letter, min, max });
+            }
+            let greens = self
+                .exact
+                .iter()
+                .filter(|slot| slot[i] == Some(true))
+                .count();
+            let open = self
+                .exact
+                .iter()
+                .filter(|slot| !is_known(slot) && slot[i] != Some(false))
+                .count();
+            if min as usize > greens + open {
+                return Err(Contradiction::NoRoom { letter });
+            }
+        }
+        let required: usize = self.counts.iter().map(|&(min, _)| min as usize).sum();
+        if required > self.word_length() {
+            return Err(Contradiction::TooManyLetters {
+                required,
+                length: self.word_length(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A change to one letter of one response in a game's history
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResponseFix {
+    /// The index of the entry in the history
+    pub entry: usize,
+    /// The position of the changed letter in the response
+    pub position: usize,
+    /// The whole response after the change
+    pub response: WordleResponse,
+}
+
+/// The changes to a single letter of a single response which would leave some of the words
+/// allowed after replaying the history on top of `info`, in history order.
+pub fn response_fixes<S: AsRef<str>>(
+    info: &Information,
+    history: &[(S, WordleResponse)],
+    words: &[&str],
+) -> Vec<ResponseFix> {
+    let mut fixes = Vec::new();
+    for (entry, (_, response)) in history.iter().enumerate() {
+        for position in 0..response.0.len() {
+            for letter in [
+                LetterResponse::Correct,
+                LetterResponse::Misplaced,
+                LetterResponse::Absent,
+            ] {
+                if response.0[position] == letter {
+                    continue;
+                }
+                let mut changed = response.clone();
+                changed.0[position] = letter;
+                let mut replayed = info.clone();
+                let consistent = history.iter().enumerate().all(|(i, (guess, response))| {
+                    let response = if i == entry { &changed } else { response };
+                    replayed.update(guess.as_ref(), response).is_ok()
+                });
+                if consistent && words.iter().any(|word| replayed.allows(word)) {
+                    fixes.push(ResponseFix {
+                        entry,
+                        position,
+                        response: changed,
+                    });
+                }
+            }
+        }
+    }
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_detects_contradictions() {
+        let mut info = Information::new();
+        info.update("sober", &"!..??".parse().unwrap()).unwrap();
+        let before = info.pattern();
+        assert_eq!(
+            info.update("sandy", &"?....".parse().unwrap()),
+            Err(Contradiction::NoLetters { position: 0 })
+        );
+        assert_eq!(info.pattern(), before);
+        assert!(matches!(
+            info.update("error", &".....".parse().unwrap()),
+            Err(Contradiction::Count { letter: 'e', .. })
+        ));
+    }
+
+    #[test]
+    fn test_contradictory_greens() {
+        let mut info = Information::new();
+        info.update("sober", &"!....".parse().unwrap()).unwrap();
+        assert_eq!(
+            info.update("tober", &"!....".parse().unwrap()),
+            Err(Contradiction::NoLetters { position: 0 })
+        );
+
+        let mut info = Information::new();
+        info.update("sober", &"?....".parse().unwrap()).unwrap();
+        assert_eq!(
+            info.update("sandy", &"!....".parse().unwrap()),
+            Err(Contradiction::NoLetters { position: 0 })
+        );
+    }
+
+    #[test]
+    fn test_response_fixes() {
+        let history = vec![
+            ("sober", "!..??".parse().unwrap()),
+            ("stage", "!.!.?".parse().unwrap()),
+        ];
+        let fixes = response_fixes(&Information::new(), &history, &["snare", "spare"]);
+        assert!(fixes.contains(&ResponseFix {
+            entry: 1,
+            position: 4,
+            response: "!.!.!".parse().unwrap(),
+        }));
+        assert!(fixes.iter().all(|fix| fix.entry == 1));
+    }
+}
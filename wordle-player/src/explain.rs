@@ -0,0 +1,213 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use crate::Information;
+
+/// A readable summary of what an [`Information`] knows about the answer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct InformationSummary {
+    /// The letter known to be at each position
+    pub greens: Vec<Option<char>>,
+    /// Letters known to be in the word outside the known positions, with the fewest extra times
+    /// each must appear, leaving out letters whose count is known exactly
+    pub required: BTreeMap<char, u8>,
+    /// Letters known not to be in the word
+    pub excluded: Vec<char>,
+    /// Letters known not to be at each position, leaving out known and excluded letters
+    pub position_exclusions: Vec<Vec<char>>,
+    /// Letters in the word whose count is known exactly
+    pub exact_counts: BTreeMap<char, u8>,
+    /// The fewest and most times a letter can appear in total, for letters whose count is
+    /// limited but not known exactly
+    pub bounds: BTreeMap<char, (u8, u8)>,
+}
+
+impl Information {
+    /// Summarize what this information says about the answer
+    pub fn summary(&self) -> InformationSummary {
+        let letter = |i: usize| (i as u8 + 97) as char;
+        let mut summary = InformationSummary {
+            greens: self
+                .exact
+                .iter()
+                .map(|slot| slot.iter().position(|&e| e == Some(true)).map(letter))
+                .collect(),
+            required: BTreeMap::new(),
+            excluded: Vec::new(),
+            position_exclusions: Vec::new(),
+            exact_counts: BTreeMap::new(),
+            bounds: BTreeMap::new(),
+        };
+        for (i, &(min, max)) in self.counts.iter().enumerate() {
+            let greens = summary
+                .greens
+                .iter()
+                .filter(|&&green| green == Some(letter(i)))
+                .count() as u8;
+            if max == 0 {
+                summary.excluded.push(letter(i));
+            } else if min == max {
+                summary.exact_counts.insert(letter(i), min);
+            } else if (max as usize) < self.word_length() {
+                summary.bounds.insert(letter(i), (min, max));
+            } else if min > greens {
+                summary.required.insert(letter(i), min - greens);
+            }
+        }
+        summary.position_exclusions = self
+            .exact
+            .iter()
+            .zip(&summary.greens)
+            .map(|(slot, green)| match green {
+                Some(_) => Vec::new(),
+                None => (0..26)
+                    .filter(|&i| slot[i] == Some(false) && self.counts[i].1 > 0)
+                    .map(letter)
+                    .collect(),
+            })
+            .collect();
+        summary
+    }
+
+    /// A compact, regex-like description of this information, such as `s[^at]a.e +r -bcdo =e`.
+    ///
+    /// Each position is written as its known letter, `[^...]` listing the letters it can't be,
+    /// or `.` if nothing is known. Then come the letters which must appear outside the known
+    /// positions, prefixed with `+`, the letters which can't appear, prefixed with `-`, and the
+    /// letters whose total count is known exactly, prefixed with `=`. Letters are repeated once
+    /// per time they must appear. Any other limits on how many times a letter appears in total
+    /// come last, written like `e{1,2}`.
+    pub fn pattern(&self) -> String {
+        self.summary().to_pattern()
+    }
+}
+
+impl InformationSummary {
+    /// The pattern described by [`Information::pattern`]
+    pub fn to_pattern(&self) -> String {
+        let mut pattern: String = self
+            .greens
+            .iter()
+            .zip(&self.position_exclusions)
+            .map(|(green, excluded)| match (green, excluded.is_empty()) {
+                (Some(c), _) => c.to_string(),
+                (None, true) => ".".to_string(),
+                (None, false) => format!("[^{}]", excluded.iter().collect::<String>()),
+            })
+            .collect();
+        let repeated = |counts: &BTreeMap<char, u8>| -> String {
+            counts
+                .iter()
+                .flat_map(|(&c, &count)| std::iter::repeat_n(c, count as usize))
+                .collect()
+        };
+        for (prefix, letters) in [
+            ('+', repeated(&self.required)),
+            ('-', self.excluded.iter().collect()),
+            ('=', repeated(&self.exact_counts)),
+        ] {
+            if !letters.is_empty() {
+                pattern.push(' ');
+                pattern.push(prefix);
+                pattern.push_str(&letters);
+            }
+        }
+        for (c, (min, max)) in &self.bounds {
+            pattern.push_str(&format!(" {}{{{},{}}}", c, min, max));
+        }
+        pattern
+    }
+}
+
+impl Display for InformationSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list =
+            |letters: &mut dyn Iterator<Item = String>| letters.collect::<Vec<_>>().join(", ");
+        writeln!(
+            f,
+            "Known:        {}",
+            self.greens
+                .iter()
+                .map(|green| green.unwrap_or('_').to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        )?;
+        if !self.required.is_empty() {
+            writeln!(
+                f,
+                "Required:     {}",
+                list(&mut self.required.iter().map(|(c, &min)| match min {
+                    1 => c.to_string(),
+                    extra => format!("{} (at least {} more)", c, extra),
+                }))
+            )?;
+        }
+        if !self.exact_counts.is_empty() {
+            writeln!(
+                f,
+                "Exact counts: {}",
+                list(
+                    &mut self
+                        .exact_counts
+                        .iter()
+                        .map(|(c, n)| format!("{} ({})", c, n))
+                )
+            )?;
+        }
+        if !self.bounds.is_empty() {
+            writeln!(
+                f,
+                "Bounds:       {}",
+                list(
+                    &mut self
+                        .bounds
+                        .iter()
+                        .map(|(c, (min, max))| format!("{} ({} to {})", c, min, max))
+                )
+            )?;
+        }
+        if !self.excluded.is_empty() {
+            writeln!(
+                f,
+                "Excluded:     {}",
+                self.excluded.iter().collect::<String>()
+            )?;
+        }
+        if self.position_exclusions.iter().any(|e| !e.is_empty()) {
+            writeln!(
+                f,
+                "Not here:     {}",
+                list(
+                    &mut self
+                        .position_exclusions
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, e)| !e.is_empty())
+                        .map(|(i, e)| format!("{}: {}", i + 1, e.iter().collect::<String>()))
+                )
+            )?;
+        }
+        write!(f, "Pattern:      {}", self.to_pattern())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern() {
+        let mut info = Information::new();
+        info.update("sober", &"!..??".parse().unwrap()).unwrap();
+        info.update("stage", &"!.!.!".parse().unwrap()).unwrap();
+        let summary = info.summary();
+        assert_eq!(
+            summary.greens,
+            vec![Some('s'), None, Some('a'), None, Some('e')]
+        );
+        assert_eq!(summary.position_exclusions[3], vec!['e']);
+        assert_eq!(info.pattern(), "s.a[^e]e +r -bgot");
+        assert_eq!(Information::new().pattern(), ".....");
+    }
+}
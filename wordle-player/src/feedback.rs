@@ -0,0 +1,146 @@
+use wordle_engine::{FeedbackModel, Standard};
+
+use crate::{bin_counts, rank_guesses, Strategy};
+
+/// Like [`Information`](crate::Information), but for games played with any [`FeedbackModel`].
+/// Rather than tracking what's known about each letter, it keeps the patterns seen and allows
+/// the words which would have given every one of them.
+#[derive(Clone, Debug)]
+pub struct FeedbackInformation<F: FeedbackModel = Standard> {
+    model: F,
+    history: Vec<(String, F::Pattern)>,
+}
+
+impl<F> FeedbackInformation<F>
+where
+    F: FeedbackModel + Sync,
+    F::Pattern: Sync,
+{
+    pub fn new(model: F) -> Self {
+        FeedbackInformation {
+            model,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn model(&self) -> &F {
+        &self.model
+    }
+
+    /// The guesses made so far, along with the patterns shown for them
+    pub fn history(&self) -> &[(String, F::Pattern)] {
+        &self.history
+    }
+
+    /// Record the pattern shown for a guess
+    pub fn update(&mut self, guess: &str, pattern: &F::Pattern) {
+        self.history.push((guess.to_string(), pattern.clone()));
+    }
+
+    /// Returns whether the word would have given every pattern seen so far. Words already
+    /// guessed are ruled out, since some models give other words the same pattern as the answer.
+    pub fn allows(&self, word: &str) -> bool {
+        self.history
+            .iter()
+            .all(|(guess, pattern)| guess != word && self.model.feedback(guess, word) == *pattern)
+    }
+
+    /// The words in the list which could be the answer
+    pub fn candidates<'a>(&self, word_list: &[&'a str]) -> Vec<&'a str> {
+        word_list
+            .iter()
+            .filter(|word| self.allows(word))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the expected bits of entropy gained by this guess
+    pub fn evaluate_guess(&self, word_list: &[&str], guess: &str) -> f64 {
+        let allowed: Vec<&str> = self.candidates(word_list);
+        Strategy::Entropy.score(&bin_counts(&self.model, guess, &allowed))
+    }
+
+    pub fn top_n_guesses<'a>(
+        &self,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        self.top_n_guesses_with(Strategy::Entropy, guess_list, word_list, count)
+    }
+
+    /// Like `top_n_guesses`, but ranking guesses by the given strategy's score
+    pub fn top_n_guesses_with<'a>(
+        &self,
+        strategy: Strategy,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        let candidates = self.candidates(word_list);
+        rank_guesses(
+            guess_list,
+            count,
+            |guess| strategy.score(&bin_counts(&self.model, guess, &candidates)),
+            |guess| self.allows(guess),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use wordle_engine::{CountOnly, GreensOnly, Naive, Peaks, WordList, WordleEngine};
+
+    const WORDS: &[&str] = &[
+        "aegis", "favor", "wired", "weird", "wires", "fired", "crane", "trace", "react", "cater",
+        "geese", "eerie",
+    ];
+
+    #[test]
+    fn test_standard_matches_information() {
+        let mut info = FeedbackInformation::new(Standard);
+        let mut expected = crate::Information::new();
+        for guess in WORDS {
+            let entropy = expected.evaluate_guess(WORDS, guess);
+            assert!((info.evaluate_guess(WORDS, guess) - entropy).abs() < 1e-9);
+        }
+        for strategy in Strategy::ALL {
+            assert_eq!(
+                info.top_n_guesses_with(strategy, WORDS, WORDS, WORDS.len()),
+                expected.top_n_guesses_with(strategy, WORDS, WORDS, WORDS.len())
+            );
+        }
+        let response = Standard.feedback("trace", "react");
+        info.update("trace", &response);
+        expected.update("trace", &response).unwrap();
+        assert_eq!(
+            info.top_n_guesses(WORDS, WORDS, WORDS.len()),
+            expected.top_n_guesses(WORDS, WORDS, WORDS.len())
+        );
+    }
+
+    fn solve_all<F>(model: F)
+    where
+        F: FeedbackModel + Copy + Sync,
+        F::Pattern: Sync,
+    {
+        let words = WordList::from_words(WORDS.iter(), 5).unwrap();
+        for answer in WORDS {
+            let game = WordleEngine::with_answer(words.clone(), answer)
+                .with_max_guesses(WORDS.len())
+                .with_feedback(model);
+            assert_solves(game, FeedbackInformation::new(model), WORDS, answer, None);
+        }
+    }
+
+    #[test]
+    fn test_solves_every_model() {
+        solve_all(Standard);
+        solve_all(GreensOnly);
+        solve_all(CountOnly);
+        solve_all(Peaks);
+        solve_all(Naive);
+    }
+}
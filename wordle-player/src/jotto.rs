@@ -0,0 +1,40 @@
+use wordle_engine::Jotto;
+
+use crate::FeedbackInformation;
+
+/// What's known about the answer of a game of Jotto. Guesses are scored by how they split the
+/// candidates up by the number of letters each shares with the guess.
+pub type JottoInformation = FeedbackInformation<Jotto>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::partition;
+    use crate::testing::assert_solves;
+    use wordle_engine::{WordList, WordleEngine};
+
+    const WORDS: &[&str] = &[
+        "crane", "react", "trace", "cater", "lymph", "moist", "fluid", "bumpy", "sixty", "knock",
+        "glyph", "dowdy",
+    ];
+
+    #[test]
+    fn test_partition() {
+        let bins = partition(&Jotto, "crane", WORDS);
+        let counts: Vec<usize> = bins.iter().map(|(count, _)| *count).collect();
+        assert_eq!(counts, [5, 4, 2, 0]);
+        assert_eq!(bins[2].1, ["knock"]);
+        assert_eq!(bins[1].1, ["react", "trace", "cater"]);
+    }
+
+    #[test]
+    fn test_solves_jotto() {
+        let words = WordList::from_words(WORDS.iter(), 5).unwrap();
+        for answer in WORDS {
+            let game = WordleEngine::with_answer(words.clone(), answer)
+                .with_max_guesses(WORDS.len())
+                .with_feedback(Jotto);
+            assert_solves(game, JottoInformation::new(Jotto), WORDS, answer, None);
+        }
+    }
+}
@@ -1,26 +1,89 @@
-use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
-use wordle_engine::{LetterResponse, WordleResponse};
+use wordle_engine::{FeedbackModel, LetterResponse, Standard, WordleResponse, DEFAULT_WORD_LENGTH};
+
+mod analysis;
+mod contradiction;
+mod explain;
+mod feedback;
+mod jotto;
+mod mastermind;
+mod nerdle;
+mod noisy;
+mod opening;
+mod pattern;
+mod peaks;
+mod session;
+mod solver;
+mod strategy;
+#[cfg(test)]
+mod testing;
+mod xordle;
+
+pub use analysis::{GameAnalysis, TurnAnalysis};
+pub use contradiction::{response_fixes, Contradiction, ResponseFix};
+pub use explain::InformationSummary;
+pub use feedback::FeedbackInformation;
+pub use jotto::JottoInformation;
+pub use mastermind::{code_paths, MastermindInformation};
+pub use nerdle::{equations, NerdleInformation};
+pub use noisy::{LieModel, NoisyInformation};
+pub use opening::{best_next_guesses, Bucket, OpeningAnalysis};
+pub use pattern::PatternError;
+pub use peaks::PeaksInformation;
+pub use session::Session;
+pub use solver::{guess_counts, regressions, Regression, Solver, Summary, TreeSearch};
+pub use strategy::Strategy;
+pub use xordle::XordleInformation;
+
+/// The longest words the solver supports, since the number of possible responses grows
+/// exponentially with the word length.
+pub const MAX_WORD_LENGTH: usize = 12;
 
 /// A struct which encapsulates the guesser's knowledge
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Information {
     counts: [(u8, u8); 26],
-    exact: [[Option<bool>; 26]; 5],
+    exact: Vec<[Option<bool>; 26]>,
 }
 
 impl Information {
-    /// Create a new struct representing no information
+    /// Create a new struct representing no information about a five-letter word
     pub fn new() -> Self {
+        Self::with_word_length(DEFAULT_WORD_LENGTH)
+    }
+
+    /// Create a new struct representing no information about a word of the given length
+    pub fn with_word_length(word_length: usize) -> Self {
+        assert!(
+            word_length <= MAX_WORD_LENGTH,
+            "Words longer than {} letters aren't supported",
+            MAX_WORD_LENGTH
+        );
         Information {
-            counts: [(0, 5); 26],
-            exact: [[None; 26]; 5],
+            counts: [(0, word_length as u8); 26],
+            exact: vec![[None; 26]; word_length],
         }
     }
 
-    /// Update this to contain the information from the given guess
-    pub fn update(&mut self, guess: &str, WordleResponse(response): WordleResponse) {
+    /// The length of the word this information is about
+    pub fn word_length(&self) -> usize {
+        self.exact.len()
+    }
+
+    /// Update this to contain the information from the given guess, unless no word could give
+    /// that response, in which case this is left unchanged.
+    pub fn update(&mut self, guess: &str, response: &WordleResponse) -> Result<(), Contradiction> {
+        let mut updated = self.clone();
+        updated.apply(guess, response);
+        updated.check()?;
+        *self = updated;
+        Ok(())
+    }
+
+    fn apply(&mut self, guess: &str, WordleResponse(response): &WordleResponse) {
         for (i, (c, response)) in guess.chars().zip(response.iter()).enumerate() {
             let char_index = c as usize - 97;
             match response {
@@ -29,8 +92,10 @@ impl Information {
                     self.exact[i][char_index] = Some(false);
                 }
                 LetterResponse::Correct => {
+                    // A letter already ruled out here stays ruled out, leaving no letters for
+                    // `check` to find
                     for c in 0..26 {
-                        self.exact[i][c] = Some(c == char_index);
+                        self.exact[i][c] = Some(c == char_index && self.exact[i][c] != Some(false));
                     }
                 }
             }
@@ -66,6 +131,17 @@ impl Information {
             })
     }
 
+    /// Returns whether or not this word may be guessed in hard mode, which requires keeping every
+    /// known letter in place and using every letter known to be present.
+    pub fn hard_mode_allows(&self, word: &str) -> bool {
+        self.exact.iter().zip(word.chars()).all(|(exact, wc)| {
+            exact.iter().all(|&e| e != Some(true)) || exact[wc as usize - 97] == Some(true)
+        }) && self.counts.iter().enumerate().all(|(i, (min, _))| {
+            let c = (i as u8 + 97) as char;
+            word.matches(c).count() >= *min as usize
+        })
+    }
+
     /// Returns the expected bits of entropy gained by this guess
     pub fn evaluate_guess(&self, word_list: &[&str], guess: &str) -> f64 {
         let allowed: Vec<&str> = word_list
@@ -78,20 +154,7 @@ impl Information {
 
     /// Like `evaluate_guess`, but the word_list must already be filtered for allowed words
     fn evaluate_guess_from_allowed(&self, word_list: &[&str], guess: &str) -> f64 {
-        let mut bins = [0; 243];
-        word_list
-            .iter()
-            .for_each(|word| bins[get_bin(guess, word)] += 1);
-        let total = bins.iter().sum::<usize>() as f64;
-        let start_entropy = total.log2();
-        bins.into_iter()
-            .filter(|&count| count != 0)
-            .map(|count| {
-                let count = count as f64;
-                let final_entropy = count.log2();
-                (start_entropy - final_entropy) * count / total
-            })
-            .sum()
+        Strategy::Entropy.score(&bin_counts(&Standard, guess, word_list))
     }
 
     /// Get the ideal guess from the given list of words
@@ -122,32 +185,26 @@ impl Information {
         allowed_words: &[&str],
         word_list: &[&'a str],
     ) -> &'a str {
-        word_list
-            .iter()
-            .map(|word| {
-                (
-                    word,
-                    self.evaluate_guess_from_allowed(allowed_words, word),
-                    self.allows(word),
-                )
-            })
-            // We pick the word which gives us the most information,
-            // breaking ties first by picking a word in the list, then
-            // by picking the word which is last alphabetically.
-            .max_by(|(_, s1, w1_in), (_, s2, w2_in)| {
-                s1.partial_cmp(s2)
-                    .expect("Unexpected NaN :(")
-                    .then_with(|| {
-                        use std::cmp::Ordering;
-                        match (w1_in, w2_in) {
-                            (false, true) => Ordering::Less,
-                            (true, false) => Ordering::Greater,
-                            _ => Ordering::Equal,
-                        }
-                    })
-            })
-            .expect("Empty word list :(")
-            .0
+        self.get_ideal_guess_with(Strategy::Entropy, allowed_words, word_list)
+    }
+
+    /// Like `get_ideal_guess_from_allowed`, but picking the guess which scores best under the
+    /// given strategy.
+    pub fn get_ideal_guess_with<'a>(
+        &self,
+        strategy: Strategy,
+        allowed_words: &[&str],
+        word_list: &[&'a str],
+    ) -> &'a str {
+        rank_guesses(
+            word_list,
+            1,
+            |word| strategy.score(&bin_counts(&Standard, word, allowed_words)),
+            |word| self.allows(word),
+        )
+        .first()
+        .expect("Empty word list :(")
+        .0
     }
 
     pub fn top_n_guesses<'a>(
@@ -155,47 +212,37 @@ impl Information {
         guess_list: &[&'a str],
         word_list: &[&'a str],
         count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        self.top_n_guesses_with(Strategy::Entropy, guess_list, word_list, count)
+    }
+
+    /// Like `top_n_guesses`, but ranking guesses by the given strategy's score. Ties are broken
+    /// as in every other solver, by picking words which could be the answer, then alphabetically.
+    pub fn top_n_guesses_with<'a>(
+        &self,
+        strategy: Strategy,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
     ) -> Vec<(&'a str, f64)> {
         let allowed_words: Vec<&'a str> = word_list
             .iter()
             .filter(|word| self.allows(word))
             .cloned()
             .collect();
-        guess_list
-            .iter()
-            .map(|word| {
-                (
-                    word,
-                    self.evaluate_guess_from_allowed(&allowed_words, word),
-                    allowed_words.contains(word),
-                )
-            })
-            // We pick the word which gives us the most information,
-            // breaking ties first by picking a word in the list, then
-            // by picking the word which is last alphabetically.
-            .sorted_unstable_by(|(_, s1, w1_in), (_, s2, w2_in)| {
-                s1.partial_cmp(s2)
-                    .expect("Unexpected NaN :(")
-                    .then_with(|| {
-                        use std::cmp::Ordering;
-                        match (w1_in, w2_in) {
-                            (false, true) => Ordering::Less,
-                            (true, false) => Ordering::Greater,
-                            _ => Ordering::Equal,
-                        }
-                    })
-                    .reverse()
-            })
-            .take(count)
-            .map(|(word, score, _)| (*word, score))
-            .collect()
+        rank_guesses(
+            guess_list,
+            count,
+            |word| strategy.score(&bin_counts(&Standard, word, &allowed_words)),
+            |word| self.allows(word),
+        )
     }
 }
 
 impl Display for Information {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Exact:")?;
-        for slot in self.exact {
+        for slot in &self.exact {
             if let Some((i, _)) = slot.iter().enumerate().find(|(_, c)| **c == Some(true)) {
                 let c = (i as u8 + 97) as char;
                 writeln!(f, "\t[ {} ]", c)?;
@@ -227,46 +274,68 @@ impl Default for Information {
     }
 }
 
-fn get_bin(guess: &str, word: &str) -> usize {
-    let mut response = [LetterResponse::Absent; 5];
-    let mut taken = [false; 5];
-    guess
-        .chars()
-        .zip(word.chars())
-        .enumerate()
-        .for_each(|(i, (gc, wc))| {
-            if wc == gc {
-                response[i] = LetterResponse::Correct;
-                taken[i] = true;
-            }
-        });
-    for (word_char, response) in guess.chars().zip(response.iter_mut()) {
-        if *response == LetterResponse::Correct {
-            continue;
-        }
-        for (solution_char, taken) in word.chars().zip(taken.iter_mut()) {
-            if !*taken && word_char == solution_char {
-                *taken = true;
-                *response = LetterResponse::Misplaced;
-                break;
-            }
-        }
+/// The number of words which would give each pattern to the guess under the feedback model,
+/// indexed by [`FeedbackModel::index`]
+pub fn bin_counts<F: FeedbackModel>(model: &F, guess: &str, words: &[&str]) -> Vec<usize> {
+    let mut bins = vec![0; model.pattern_count(guess.len())];
+    words
+        .iter()
+        .for_each(|word| bins[model.feedback_index(guess, word)] += 1);
+    bins
+}
+
+/// Split the words by the pattern each would give to the guess under the feedback model, from
+/// the highest [`FeedbackModel::index`] down, which for the built-in models starts with the
+/// all-correct pattern.
+pub fn partition<'a, F: FeedbackModel>(
+    model: &F,
+    guess: &str,
+    words: &[&'a str],
+) -> Vec<(F::Pattern, Vec<&'a str>)> {
+    let mut bins: BTreeMap<usize, (F::Pattern, Vec<&'a str>)> = BTreeMap::new();
+    for word in words {
+        let pattern = model.feedback(guess, word);
+        bins.entry(model.index(&pattern))
+            .or_insert_with(|| (pattern, Vec::new()))
+            .1
+            .push(word);
     }
-    response.into_iter().fold(0, |acc, resp| {
-        acc * 3
-            + match resp {
-                LetterResponse::Absent => 0,
-                LetterResponse::Misplaced => 1,
-                LetterResponse::Correct => 2,
-            }
-    })
+    bins.into_values().rev().collect()
+}
+
+/// The `count` best guesses by their score, breaking ties by picking words which could be the
+/// answer, then alphabetically. Every solver ranks its guesses with this.
+pub(crate) fn rank_guesses<'a>(
+    guess_list: &[&'a str],
+    count: usize,
+    score: impl Fn(&str) -> f64 + Sync,
+    could_be_answer: impl Fn(&str) -> bool + Sync,
+) -> Vec<(&'a str, f64)> {
+    let mut scores: Vec<(&'a str, f64, bool)> = guess_list
+        .par_iter()
+        .map(|&guess| (guess, score(guess), could_be_answer(guess)))
+        .collect();
+    scores.sort_by(|(w1, s1, in1), (w2, s2, in2)| {
+        s2.total_cmp(s1)
+            .then_with(|| in2.cmp(in1))
+            .then_with(|| w1.cmp(w2))
+    });
+    scores
+        .into_iter()
+        .take(count)
+        .map(|(word, score, _)| (word, score))
+        .collect()
+}
+
+fn get_bin(guess: &str, word: &str) -> usize {
+    Standard.feedback_index(guess, word)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const WORD_LIST: &'static [&'static str] = &["aegis", "favor", "wired", "weird"];
+    const WORD_LIST: &[&str] = &["aegis", "favor", "wired", "weird"];
     #[test]
     /// Tests that no information means all words allowed
     fn test_allow_default() {
@@ -288,9 +357,19 @@ mod tests {
         let mut info = Information::new();
         info.update(
             "bibbs",
-            WordleResponse([Misplaced, Absent, Correct, Absent, Absent]),
-        );
+            &WordleResponse(vec![Misplaced, Absent, Correct, Absent, Absent]),
+        )
+        .unwrap();
         assert!(info.allows("abbey"));
         assert!(!info.allows("abbes"));
     }
+
+    #[test]
+    fn test_hard_mode_allows() {
+        let mut info = Information::new();
+        info.update("crate", &"?!!?!".parse().unwrap()).unwrap();
+        assert!(info.hard_mode_allows("trace"));
+        assert!(!info.hard_mode_allows("crane"));
+        assert!(!info.hard_mode_allows("slate"));
+    }
 }
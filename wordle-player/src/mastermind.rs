@@ -0,0 +1,78 @@
+use wordle_engine::CountOnly;
+
+use crate::{partition, FeedbackInformation, Strategy};
+
+/// What's known about the secret code of a game of Mastermind, from the black and white peg
+/// counts of each guess
+pub type MastermindInformation = FeedbackInformation<CountOnly>;
+
+/// The guesses made for each of the codes by always making the best guess from them under the
+/// strategy, in the form taken by [`Summary::from_paths`](crate::Summary).
+pub fn code_paths<'a>(codes: &[&'a str], strategy: Strategy) -> Vec<(&'a str, Vec<&'a str>)> {
+    let mut paths = Vec::new();
+    add_paths(
+        &MastermindInformation::new(CountOnly),
+        codes,
+        codes,
+        strategy,
+        &[],
+        &mut paths,
+    );
+    paths
+}
+
+/// Add the paths to every code in `remaining`, which must be the codes allowed by `info`, after
+/// the guesses in `path`
+fn add_paths<'a>(
+    info: &MastermindInformation,
+    codes: &[&'a str],
+    remaining: &[&'a str],
+    strategy: Strategy,
+    path: &[&'a str],
+    paths: &mut Vec<(&'a str, Vec<&'a str>)>,
+) {
+    let (guess, _) = info.top_n_guesses_with(strategy, codes, remaining, 1)[0];
+    let mut path = path.to_vec();
+    path.push(guess);
+    for (pegs, remaining) in partition(&CountOnly, guess, remaining) {
+        if pegs.correct == guess.len() {
+            paths.push((guess, path.clone()));
+        } else {
+            let mut info = info.clone();
+            info.update(guess, &pegs);
+            add_paths(&info, codes, &remaining, strategy, &path, paths);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use crate::Summary;
+    use wordle_engine::{Mastermind, MastermindEngine};
+
+    #[test]
+    fn test_knuth_five_guesses() {
+        let codes = Mastermind::new(4, 6).codes();
+        let codes: Vec<&str> = codes.iter().map(String::as_str).collect();
+        let paths = code_paths(&codes, Strategy::Minimax);
+        // Knuth's opening of 1122
+        assert!(paths.iter().all(|(_, path)| path[0] == "aabb"));
+        let summary = Summary::from_paths(&paths);
+        assert_eq!(summary.answers, 1296);
+        assert_eq!(summary.worst_case, 5);
+    }
+
+    #[test]
+    fn test_solves_without_duplicates() {
+        let rules = Mastermind::new(4, 6).with_duplicates(false);
+        let codes = rules.codes();
+        let codes: Vec<&str> = codes.iter().map(String::as_str).collect();
+        for secret in codes.iter().step_by(17) {
+            let game = MastermindEngine::with_secret(rules, secret).unwrap();
+            let info = MastermindInformation::new(CountOnly);
+            assert_solves(game, info, &codes, secret, None);
+        }
+    }
+}
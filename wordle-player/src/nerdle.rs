@@ -0,0 +1,107 @@
+use wordle_engine::{Nerdle, Standard};
+
+use crate::FeedbackInformation;
+
+/// What's known about the answer of a game of Nerdle, from the standard feedback to each guess
+pub type NerdleInformation = FeedbackInformation<Standard>;
+
+/// Every valid Nerdle equation of the given length, in alphabetical order, for use as both the
+/// guess list and the possible answers, since there's no word list to load.
+///
+/// Rather than checking every string of symbols, this builds each possible left side and
+/// writes the number it evaluates to on the right.
+pub fn equations(length: usize) -> Vec<String> {
+    let mut equations = Vec::new();
+    // The right side takes at least one symbol and the `=` another
+    for left_length in 1..length.saturating_sub(1) {
+        add_expressions(&mut String::new(), left_length, false, &mut |left| {
+            if let Some(value) = Nerdle::evaluate(left) {
+                let right = value.to_string();
+                if value >= 0 && left.len() + 1 + right.len() == length {
+                    equations.push(format!("{}={}", left, right));
+                }
+            }
+        });
+    }
+    equations.sort();
+    equations
+}
+
+/// Call `found` with every expression starting with `prefix` which has `remaining` more
+/// symbols, and at least one operator unless `has_operator`
+fn add_expressions(
+    prefix: &mut String,
+    remaining: usize,
+    has_operator: bool,
+    found: &mut impl FnMut(&str),
+) {
+    let start = prefix.len();
+    for digits in 1..=remaining {
+        let after = remaining - digits;
+        // Another number needs an operator and at least one digit
+        if (after == 0 && !has_operator) || after == 1 {
+            continue;
+        }
+        let first = if digits == 1 {
+            0
+        } else {
+            10u64.pow(digits as u32 - 1)
+        };
+        for number in first..10u64.pow(digits as u32) {
+            prefix.truncate(start);
+            prefix.push_str(&number.to_string());
+            if after == 0 {
+                found(prefix);
+                continue;
+            }
+            for operator in ['+', '-', '*', '/'] {
+                prefix.truncate(start + digits);
+                prefix.push(operator);
+                add_expressions(prefix, after - 1, true, found);
+            }
+        }
+    }
+    prefix.truncate(start);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use wordle_engine::NerdleEngine;
+
+    #[test]
+    fn test_equations() {
+        let rules = Nerdle::new(5);
+        let symbols: Vec<char> = wordle_engine::NERDLE_SYMBOLS.chars().collect();
+        let every_string = (0..symbols.len().pow(5)).map(|mut index| {
+            (0..5)
+                .map(|_| {
+                    let symbol = symbols[index % symbols.len()];
+                    index /= symbols.len();
+                    symbol
+                })
+                .collect::<String>()
+        });
+        let mut expected: Vec<String> = every_string.filter(|s| rules.is_valid(s)).collect();
+        expected.sort();
+        assert_eq!(equations(5), expected);
+        assert!(equations(8)
+            .iter()
+            .all(|equation| Nerdle::default().is_valid(equation)));
+    }
+
+    #[test]
+    fn test_solves_nerdle() {
+        let equations = equations(6);
+        let equations: Vec<&str> = equations.iter().map(String::as_str).collect();
+        // The first guess is the same for every answer, so only find it once
+        let (opener, _) =
+            NerdleInformation::new(Standard).top_n_guesses(&equations, &equations, 1)[0];
+        for answer in equations.iter().step_by(3) {
+            let game = NerdleEngine::with_equation(answer).unwrap();
+            let info = NerdleInformation::new(Standard);
+            assert_solves(game, info, &equations, answer, Some(opener));
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::ops::RangeInclusive;
+
+use wordle_engine::WordleResponse;
+
+use crate::{get_bin, rank_guesses, Strategy};
+
+/// Which tiles of the responses may be lies
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LieModel {
+    /// Exactly this many tiles of each response are lies, as in Fibble, except that a correct
+    /// guess gets a truthful response
+    PerResponse(usize),
+    /// Up to this many tiles are lies over the whole game
+    Total(usize),
+}
+
+/// Like [`Information`](crate::Information), but for responses which may contain lies. Rather
+/// than ruling words out letter by letter, it keeps the responses and counts the lies each
+/// possible answer would imply, allowing the words which fit the [`LieModel`].
+#[derive(Clone, Debug)]
+pub struct NoisyInformation {
+    model: LieModel,
+    history: Vec<(String, WordleResponse)>,
+}
+
+/// The number of tiles which differ between two responses
+fn distance(a: &WordleResponse, b: &WordleResponse) -> usize {
+    a.0.iter().zip(&b.0).filter(|(a, b)| a != b).count()
+}
+
+/// Call `shown` with the index of every response which differs from the one with the given
+/// index at exactly `lies` of the positions from `position` on
+fn for_each_lie(
+    shown: &mut impl FnMut(usize),
+    index: usize,
+    position: usize,
+    length: usize,
+    lies: usize,
+) {
+    if lies == 0 {
+        shown(index);
+        return;
+    }
+    if length - position < lies {
+        return;
+    }
+    for_each_lie(shown, index, position + 1, length, lies);
+    let place = 3usize.pow((length - 1 - position) as u32);
+    let digit = index / place % 3;
+    for other in (0..3).filter(|&other| other != digit) {
+        let changed = index - digit * place + other * place;
+        for_each_lie(shown, changed, position + 1, length, lies - 1);
+    }
+}
+
+/// The number of ways to lie about `lies` of the tiles of a response with the given length
+fn lie_patterns(length: usize, lies: usize) -> usize {
+    if lies > length {
+        return 0;
+    }
+    (0..lies).fold(1, |ways, i| ways * (length - i) / (i + 1)) * 2usize.pow(lies as u32)
+}
+
+impl NoisyInformation {
+    pub fn new(model: LieModel) -> Self {
+        NoisyInformation {
+            model,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn model(&self) -> LieModel {
+        self.model
+    }
+
+    /// Record the response shown for a guess, lies and all
+    pub fn update(&mut self, guess: &str, response: &WordleResponse) {
+        self.history.push((guess.to_string(), response.clone()));
+    }
+
+    /// The number of lies the responses so far would contain if the word were the answer, or
+    /// `None` if the model doesn't allow them
+    pub fn lies(&self, word: &str) -> Option<usize> {
+        let mut total = 0;
+        for (guess, shown) in &self.history {
+            let truth = WordleResponse::from_index(get_bin(guess, word), guess.len());
+            let lies = distance(shown, &truth);
+            if let LieModel::PerResponse(count) = self.model {
+                let expected = if guess == word { 0 } else { count };
+                if lies != expected {
+                    return None;
+                }
+            }
+            total += lies;
+        }
+        match self.model {
+            LieModel::Total(count) if total > count => None,
+            _ => Some(total),
+        }
+    }
+
+    /// Returns whether the word could be the answer, given the lies the model allows
+    pub fn allows(&self, word: &str) -> bool {
+        self.lies(word).is_some()
+    }
+
+    /// The words which could be the answer, each with the number of lies it implies
+    pub fn candidates<'a>(&self, word_list: &[&'a str]) -> Vec<(&'a str, usize)> {
+        word_list
+            .iter()
+            .filter_map(|&word| self.lies(word).map(|lies| (word, lies)))
+            .collect()
+    }
+
+    /// The numbers of lies the next response could have if the word were the answer, given the
+    /// lies it already implies
+    fn next_lies(&self, guess: &str, word: &str, lies: usize) -> RangeInclusive<usize> {
+        match self.model {
+            _ if guess == word => 0..=0,
+            LieModel::PerResponse(count) => count..=count,
+            LieModel::Total(count) => 0..=count - lies,
+        }
+    }
+
+    /// Score the guess by how it splits up the candidates, treating every candidate as equally
+    /// likely, and every response it could show as equally likely for that candidate. Under
+    /// [`LieModel::Total`], each candidate may lie about as many tiles as it has lies left, so
+    /// with no lies this scores guesses like [`Information`](crate::Information). A lie never
+    /// makes the whole response green, since that would look like a win.
+    ///
+    /// Entropy scores are the bits of information about the answer, leaving out the randomness
+    /// of where the lies fall, and the other strategies count the candidates which could show
+    /// each response.
+    fn score(&self, strategy: Strategy, guess: &str, candidates: &[(&str, usize)]) -> f64 {
+        let length = guess.len();
+        let correct = WordleResponse::correct(length).index();
+        // The number of candidates which could show each response, and how likely each response
+        // is, where each candidate adds up to one
+        let mut counts = vec![0; 3usize.pow(length as u32)];
+        let mut weights = vec![0.0; counts.len()];
+        let mut noise = 0.0;
+        for &(word, lies) in candidates {
+            let truth = get_bin(guess, word);
+            let next_lies = self.next_lies(guess, word, lies);
+            let wrong_tiles = (0..length)
+                .filter(|&i| truth / 3usize.pow(i as u32) % 3 != 2)
+                .count();
+            // Lying about exactly the wrong tiles can make them all green, which isn't shown
+            let shows_correct = truth != correct && next_lies.contains(&wrong_tiles);
+            let patterns = next_lies
+                .clone()
+                .map(|lies| lie_patterns(length, lies))
+                .sum::<usize>()
+                - shows_correct as usize;
+            let mut shown = |index| {
+                if index != correct || truth == correct {
+                    counts[index] += 1;
+                    weights[index] += 1.0 / patterns as f64;
+                }
+            };
+            for lies in next_lies {
+                for_each_lie(&mut shown, truth, 0, length, lies);
+            }
+            noise += (patterns as f64).log2();
+        }
+        let total = candidates.len() as f64;
+        let seen = || {
+            counts
+                .iter()
+                .zip(&weights)
+                .filter(|(_, &weight)| weight != 0.0)
+                .map(|(&count, &weight)| (count as f64, weight))
+        };
+        match strategy {
+            Strategy::Entropy => {
+                let entropy: f64 = seen()
+                    .map(|(_, weight)| weight / total * (total / weight).log2())
+                    .sum();
+                entropy - noise / total.max(1.0)
+            }
+            Strategy::Minimax => -seen().fold(0.0, |worst, (count, _)| f64::max(worst, count)),
+            Strategy::ExpectedSize => {
+                -seen().map(|(count, weight)| count * weight).sum::<f64>() / total
+            }
+        }
+    }
+
+    /// Returns the expected bits of entropy gained by this guess, over the words in the list
+    /// which could be the answer
+    pub fn evaluate_guess(&self, word_list: &[&str], guess: &str) -> f64 {
+        self.score(Strategy::Entropy, guess, &self.candidates(word_list))
+    }
+
+    pub fn top_n_guesses<'a>(
+        &self,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        self.top_n_guesses_with(Strategy::Entropy, guess_list, word_list, count)
+    }
+
+    /// Like `top_n_guesses`, but ranking guesses by the given strategy's score
+    pub fn top_n_guesses_with<'a>(
+        &self,
+        strategy: Strategy,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        let candidates = self.candidates(word_list);
+        rank_guesses(
+            guess_list,
+            count,
+            |guess| self.score(strategy, guess, &candidates),
+            |guess| self.allows(guess),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use wordle_engine::{FibbleEngine, WordList, WordleEngine};
+
+    const WORDS: &[&str] = &[
+        "aegis", "favor", "wired", "weird", "wires", "fired", "crane", "trace", "react", "cater",
+    ];
+
+    #[test]
+    fn test_lie_counts() {
+        let mut info = NoisyInformation::new(LieModel::Total(1));
+        // The truthful response to "wired" for "wires" is "!!!!.", so this has one lie
+        info.update("wired", &"!!!!!".parse().unwrap());
+        assert_eq!(info.lies("wired"), Some(0));
+        assert_eq!(info.lies("wires"), Some(1));
+        assert_eq!(info.lies("crane"), None);
+
+        let mut fibble = NoisyInformation::new(LieModel::PerResponse(1));
+        fibble.update("wired", &"!!!!?".parse().unwrap());
+        assert!(!fibble.allows("wired"));
+        assert!(fibble.allows("wires"));
+        assert!(!fibble.allows("fired"));
+
+        let truthful = NoisyInformation::new(LieModel::Total(0));
+        for guess in WORDS {
+            let expected = crate::Information::new().evaluate_guess(WORDS, guess);
+            assert!((truthful.evaluate_guess(WORDS, guess) - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_lie_patterns() {
+        for lies in 0..=3 {
+            let mut bins = vec![0; 243];
+            for_each_lie(&mut |index| bins[index] += 1, 100, 0, 5, lies);
+            assert_eq!(bins.iter().sum::<usize>(), lie_patterns(5, lies));
+            assert!(bins.iter().all(|&count| count <= 1));
+        }
+    }
+
+    #[test]
+    fn test_scores_follow_the_model() {
+        // The truthful responses to "fired" for these are ".!!!!" and ".!!!.", one lie apart
+        let total = NoisyInformation::new(LieModel::Total(1));
+        let fresh = [("wired", 0), ("wires", 0)];
+        let spent = [("wired", 1), ("wires", 1)];
+        assert_eq!(total.score(Strategy::Minimax, "fired", &fresh), -2.0);
+        assert_eq!(total.score(Strategy::Minimax, "fired", &spent), -1.0);
+        assert!((total.score(Strategy::Entropy, "fired", &spent) - 1.0).abs() < 1e-9);
+
+        // A lie about "wired" can't make it look like "wires" was guessed correctly, so the
+        // guess tells them apart
+        let fibble = NoisyInformation::new(LieModel::PerResponse(1));
+        assert_eq!(fibble.score(Strategy::Minimax, "wires", &fresh), -1.0);
+        assert!((fibble.score(Strategy::Entropy, "wires", &fresh) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solves_fibble() {
+        let words = WordList::from_words(WORDS.iter(), 5).unwrap();
+        for (seed, answer) in WORDS.iter().enumerate() {
+            let game = FibbleEngine::new(
+                WordleEngine::with_answer(words.clone(), answer).with_max_guesses(10),
+            )
+            .with_seed(seed as u64);
+            let info = NoisyInformation::new(LieModel::PerResponse(1));
+            assert_solves(game, info, WORDS, answer, None);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+use wordle_engine::WordleResponse;
+
+use crate::{get_bin, Strategy};
+
+/// How a fixed sequence of opening guesses, played no matter what the responses are, splits up
+/// the answers
+#[derive(Clone, Debug, Serialize)]
+pub struct OpeningAnalysis<'a> {
+    pub guesses: Vec<String>,
+    /// The answers grouped by the responses they give to every guess, largest group first
+    pub buckets: Vec<Bucket<'a>>,
+    /// The expected bits of information from the whole sequence
+    pub entropy: f64,
+    /// The number of buckets of each size
+    pub bucket_sizes: BTreeMap<usize, usize>,
+    /// The number of answers which are the only answer in their bucket, so are known after the
+    /// opening
+    pub unique: usize,
+}
+
+/// The answers which give the same response to every guess in an opening
+#[derive(Clone, Debug, Serialize)]
+pub struct Bucket<'a> {
+    /// The response to each guess, in order
+    pub responses: Vec<String>,
+    pub answers: Vec<&'a str>,
+}
+
+impl<'a> OpeningAnalysis<'a> {
+    /// Analyze playing the given guesses, in order, against each of the answers
+    pub fn new(guesses: &[&str], answers: &[&'a str]) -> Self {
+        let mut groups: HashMap<Vec<usize>, Vec<&'a str>> = HashMap::new();
+        for answer in answers {
+            let key = guesses.iter().map(|guess| get_bin(guess, answer)).collect();
+            groups.entry(key).or_default().push(answer);
+        }
+        let mut buckets: Vec<(Vec<usize>, Vec<&'a str>)> = groups.into_iter().collect();
+        buckets.sort_by(|(k1, a1), (k2, a2)| a2.len().cmp(&a1.len()).then_with(|| k2.cmp(k1)));
+        let sizes: Vec<usize> = buckets.iter().map(|(_, answers)| answers.len()).collect();
+        let mut bucket_sizes = BTreeMap::new();
+        for &size in &sizes {
+            *bucket_sizes.entry(size).or_insert(0) += 1;
+        }
+        OpeningAnalysis {
+            guesses: guesses.iter().map(|guess| guess.to_string()).collect(),
+            entropy: Strategy::Entropy.score(&sizes),
+            unique: bucket_sizes.get(&1).cloned().unwrap_or(0),
+            bucket_sizes,
+            buckets: buckets
+                .into_iter()
+                .map(|(key, answers)| Bucket {
+                    responses: key
+                        .into_iter()
+                        .zip(guesses)
+                        .map(|(bin, guess)| {
+                            WordleResponse::from_index(bin, guess.len()).to_string()
+                        })
+                        .collect(),
+                    answers,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The best words to play after the fixed guesses, scored by how the whole sequence splits up the
+/// answers under the strategy, best first.
+///
+/// With no fixed guesses this ranks openers, and with one it finds the best second word to pair
+/// with a fixed first word.
+pub fn best_next_guesses<'a>(
+    strategy: Strategy,
+    fixed: &[&str],
+    guess_list: &[&'a str],
+    answers: &[&str],
+    count: usize,
+) -> Vec<(&'a str, f64)> {
+    // Number the buckets from the fixed guesses, so each candidate only needs one more bin per
+    // answer.
+    let mut bucket_ids: HashMap<Vec<usize>, usize> = HashMap::new();
+    let fixed_buckets: Vec<usize> = answers
+        .iter()
+        .map(|answer| {
+            let key: Vec<usize> = fixed.iter().map(|guess| get_bin(guess, answer)).collect();
+            let next_id = bucket_ids.len();
+            *bucket_ids.entry(key).or_insert(next_id)
+        })
+        .collect();
+    let mut scores: Vec<(&'a str, f64)> = guess_list
+        .par_iter()
+        .map(|&guess| {
+            let bins = 3usize.pow(guess.len() as u32);
+            let mut keys: Vec<usize> = answers
+                .iter()
+                .zip(&fixed_buckets)
+                .map(|(answer, bucket)| bucket * bins + get_bin(guess, answer))
+                .collect();
+            keys.sort_unstable();
+            let sizes: Vec<usize> = keys
+                .chunk_by(|a, b| a == b)
+                .map(|chunk| chunk.len())
+                .collect();
+            (guess, strategy.score(&sizes))
+        })
+        .collect();
+    scores.sort_by(|(w1, s1), (w2, s2)| s2.total_cmp(s1).then_with(|| w1.cmp(w2)));
+    scores.truncate(count);
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &[&str] = &["aegis", "favor", "wired", "weird", "wires", "fired"];
+
+    #[test]
+    fn test_joint_partition() {
+        let single = OpeningAnalysis::new(&["wired"], ANSWERS);
+        let pair = OpeningAnalysis::new(&["wired", "favor"], ANSWERS);
+        assert!(pair.entropy >= single.entropy);
+        assert_eq!(
+            pair.buckets.iter().map(|b| b.answers.len()).sum::<usize>(),
+            6
+        );
+        assert_eq!(
+            pair.unique,
+            pair.buckets.iter().filter(|b| b.answers.len() == 1).count()
+        );
+        assert_eq!(pair.buckets[0].responses.len(), 2);
+    }
+
+    #[test]
+    fn test_best_next_matches_analysis() {
+        let best = best_next_guesses(Strategy::Entropy, &["wired"], ANSWERS, ANSWERS, 1);
+        let analysis = OpeningAnalysis::new(&["wired", best[0].0], ANSWERS);
+        assert!((analysis.entropy - best[0].1).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,282 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::{Contradiction, Information, MAX_WORD_LENGTH};
+
+/// An error from parsing an [`Information`] from a constraint pattern
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PatternError {
+    /// The pattern didn't describe any positions
+    Empty,
+    /// The pattern described more positions than the solver supports
+    TooLong(usize),
+    /// A character other than a letter or the pattern syntax was found
+    UnexpectedCharacter(char),
+    /// A `[^...]` position was never closed
+    UnclosedBracket,
+    /// A count bound such as `e{1,2}` couldn't be parsed
+    InvalidBound(String),
+    /// No word could satisfy the constraints
+    Contradiction(Contradiction),
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "The pattern is empty"),
+            PatternError::TooLong(length) => write!(
+                f,
+                "The pattern has {} positions, but at most {} are supported",
+                length, MAX_WORD_LENGTH
+            ),
+            PatternError::UnexpectedCharacter(c) => {
+                write!(f, "Unexpected {:?} in pattern", c)
+            }
+            PatternError::UnclosedBracket => write!(f, "Missing ']' in pattern"),
+            PatternError::InvalidBound(token) => write!(
+                f,
+                "Can't parse {:?}, expected a letter and bounds such as e{{1,2}}",
+                token
+            ),
+            PatternError::Contradiction(contradiction) => write!(f, "{}", contradiction),
+        }
+    }
+}
+
+impl Error for PatternError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PatternError::Contradiction(contradiction) => Some(contradiction),
+            _ => None,
+        }
+    }
+}
+
+impl From<Contradiction> for PatternError {
+    fn from(contradiction: Contradiction) -> Self {
+        PatternError::Contradiction(contradiction)
+    }
+}
+
+/// What a pattern says about one position
+enum Position {
+    Known(usize),
+    Excluded(Vec<usize>),
+}
+
+fn letter_index(c: char) -> Result<usize, PatternError> {
+    if c.is_ascii_lowercase() {
+        Ok(c as usize - 97)
+    } else {
+        Err(PatternError::UnexpectedCharacter(c))
+    }
+}
+
+fn letter_indices(letters: &str) -> Result<Vec<usize>, PatternError> {
+    letters.chars().map(letter_index).collect()
+}
+
+fn parse_positions(token: &str) -> Result<Vec<Position>, PatternError> {
+    let mut positions = Vec::new();
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        positions.push(match c {
+            '.' => Position::Excluded(Vec::new()),
+            '[' => {
+                if chars.next() != Some('^') {
+                    return Err(PatternError::UnexpectedCharacter('['));
+                }
+                let mut letters = Vec::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => letters.push(letter_index(c)?),
+                        None => return Err(PatternError::UnclosedBracket),
+                    }
+                }
+                Position::Excluded(letters)
+            }
+            c => Position::Known(letter_index(c)?),
+        });
+    }
+    Ok(positions)
+}
+
+/// Parses a bound such as `e{1,2}`, `e{2}` or `e{1,}` into the letter and its count range
+fn parse_bound(token: &str, length: u8) -> Result<(usize, u8, u8), PatternError> {
+    let invalid = || PatternError::InvalidBound(token.to_string());
+    let mut chars = token.chars();
+    let letter = letter_index(chars.next().ok_or_else(invalid)?)?;
+    let bounds = chars
+        .as_str()
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(invalid)?;
+    let parse = |n: &str| n.trim().parse::<u8>().map_err(|_| invalid());
+    let (min, max) = match bounds.split_once(',') {
+        Some((min, max)) if max.trim().is_empty() => (parse(min)?, length),
+        Some((min, max)) => (parse(min)?, parse(max)?),
+        None => (parse(bounds)?, parse(bounds)?),
+    };
+    Ok((letter, min, max.min(length)))
+}
+
+impl FromStr for Information {
+    type Err = PatternError;
+
+    /// Parses the constraint pattern written by [`Information::pattern`], along with bounds on
+    /// the total number of times a letter appears, written like `e{1,2}`, `e{2}` or `e{1,}`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let positions = parse_positions(tokens.next().ok_or(PatternError::Empty)?)?;
+        if positions.is_empty() {
+            return Err(PatternError::Empty);
+        } else if positions.len() > MAX_WORD_LENGTH {
+            return Err(PatternError::TooLong(positions.len()));
+        }
+        let length = positions.len() as u8;
+        let mut info = Information::with_word_length(positions.len());
+        let mut greens = [0u8; 26];
+        for (slot, position) in info.exact.iter_mut().zip(&positions) {
+            match position {
+                Position::Known(letter) => {
+                    greens[*letter] += 1;
+                    for (i, exact) in slot.iter_mut().enumerate() {
+                        *exact = Some(i == *letter);
+                    }
+                }
+                Position::Excluded(letters) => {
+                    for &letter in letters {
+                        slot[letter] = Some(false);
+                    }
+                }
+            }
+        }
+        let mut required = greens;
+        let mut bound = |letter: usize, min: u8, max: u8| {
+            let count = &mut info.counts[letter];
+            *count = (count.0.max(min), count.1.min(max));
+        };
+        for token in tokens {
+            match token.chars().next() {
+                Some('+') => {
+                    for letter in letter_indices(&token[1..])? {
+                        required[letter] = required[letter].saturating_add(1);
+                    }
+                }
+                Some('-') => {
+                    for letter in letter_indices(&token[1..])? {
+                        bound(letter, 0, 0);
+                    }
+                }
+                Some('=') => {
+                    let mut exact = [0u8; 26];
+                    for letter in letter_indices(&token[1..])? {
+                        exact[letter] = exact[letter].saturating_add(1);
+                    }
+                    for (letter, &count) in exact.iter().enumerate() {
+                        if count > 0 {
+                            bound(letter, count, count);
+                        }
+                    }
+                }
+                _ => {
+                    let (letter, min, max) = parse_bound(token, length)?;
+                    bound(letter, min, max);
+                }
+            }
+        }
+        for (count, &required) in info.counts.iter_mut().zip(&required) {
+            count.0 = count.0.max(required);
+        }
+        info.check()?;
+        Ok(info)
+    }
+}
+
+/// Information is stored as its pattern, which is stable across versions
+impl Serialize for Information {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.pattern())
+    }
+}
+
+impl<'de> Deserialize<'de> for Information {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: &[&str] = &[
+        "saree", "sabre", "spare", "shape", "stale", "snare", "scare",
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        let mut info = Information::new();
+        info.update("sober", &"!..??".parse().unwrap()).unwrap();
+        info.update("stage", &"!.!.!".parse().unwrap()).unwrap();
+        let parsed: Information = info.pattern().parse().unwrap();
+        assert_eq!(parsed.pattern(), info.pattern());
+        for word in WORDS {
+            assert_eq!(parsed.allows(word), info.allows(word), "{}", word);
+        }
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(json, format!("{:?}", info.pattern()));
+        let deserialized: Information = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.pattern(), info.pattern());
+        assert!(serde_json::from_str::<Information>("\"s[^a\"").is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let info: Information = "sa..e e{2} r{0,}".parse().unwrap();
+        assert!(info.allows("saree"));
+        assert!(!info.allows("sabre"));
+        let info: Information = "s...e e{1,2}".parse().unwrap();
+        assert_eq!(info.pattern(), "s...e e{1,2}");
+        let info: Information = "....e +e".parse().unwrap();
+        assert!(info.allows("saree"));
+        assert!(!info.allows("stale"));
+        assert_eq!(info.pattern(), "....e +e");
+    }
+
+    #[test]
+    fn test_contradictions() {
+        assert_eq!(
+            "s.a.e -s".parse::<Information>().unwrap_err(),
+            PatternError::Contradiction(Contradiction::Count {
+                letter: 's',
+                min: 1,
+                max: 0
+            })
+        );
+        assert_eq!(
+            "[^r][^r]ar. +rr".parse::<Information>().unwrap_err(),
+            PatternError::Contradiction(Contradiction::NoRoom { letter: 'r' })
+        );
+        assert_eq!(
+            "s.... +abcde".parse::<Information>().unwrap_err(),
+            PatternError::Contradiction(Contradiction::TooManyLetters {
+                required: 6,
+                length: 5
+            })
+        );
+        assert_eq!(
+            "s[^ab".parse::<Information>().unwrap_err(),
+            PatternError::UnclosedBracket
+        );
+        assert!(matches!(
+            "s.... e{x}".parse::<Information>(),
+            Err(PatternError::InvalidBound(_))
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+use wordle_engine::{Peaks, PeaksLetter, PeaksResponse, DEFAULT_WORD_LENGTH};
+
+use crate::{bin_counts, rank_guesses, Contradiction, Strategy};
+
+/// What's known about the answer of a game of Wordle Peaks. Each response only says how the
+/// answer's letter at each position compares to the guess's, so what's known is the range of
+/// letters still possible at each position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeaksInformation {
+    /// The first and last letters possible at each position, as bytes
+    intervals: Vec<(u8, u8)>,
+}
+
+impl PeaksInformation {
+    /// Create a new struct representing no information about a five-letter word
+    pub fn new() -> Self {
+        Self::with_word_length(DEFAULT_WORD_LENGTH)
+    }
+
+    /// Create a new struct representing no information about a word of the given length
+    pub fn with_word_length(word_length: usize) -> Self {
+        PeaksInformation {
+            intervals: vec![(b'a', b'z'); word_length],
+        }
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// The letters the answer could have at the position, counting from 0
+    pub fn interval(&self, position: usize) -> RangeInclusive<char> {
+        let (first, last) = self.intervals[position];
+        first as char..=last as char
+    }
+
+    /// Narrow the intervals using the response to the guess, unless that would leave no letters
+    /// at some position, in which case this is left unchanged.
+    pub fn update(&mut self, guess: &str, response: &PeaksResponse) -> Result<(), Contradiction> {
+        let mut intervals = self.intervals.clone();
+        for (position, ((first, last), (c, letter))) in intervals
+            .iter_mut()
+            .zip(guess.bytes().zip(&response.0))
+            .enumerate()
+        {
+            match letter {
+                PeaksLetter::Correct => (*first, *last) = ((*first).max(c), (*last).min(c)),
+                PeaksLetter::Earlier => *last = (*last).min(c.saturating_sub(1)),
+                PeaksLetter::Later => *first = (*first).max(c.saturating_add(1)),
+            }
+            if first > last {
+                return Err(Contradiction::NoLetters { position });
+            }
+        }
+        self.intervals = intervals;
+        Ok(())
+    }
+
+    /// Returns whether the word has a possible letter at every position
+    pub fn allows(&self, word: &str) -> bool {
+        word.len() == self.intervals.len()
+            && word
+                .bytes()
+                .zip(&self.intervals)
+                .all(|(c, &(first, last))| (first..=last).contains(&c))
+    }
+
+    /// Returns the expected bits of entropy gained by this guess
+    pub fn evaluate_guess(&self, word_list: &[&str], guess: &str) -> f64 {
+        let allowed: Vec<&str> = word_list
+            .iter()
+            .filter(|word| self.allows(word))
+            .cloned()
+            .collect();
+        Strategy::Entropy.score(&bin_counts(&Peaks, guess, &allowed))
+    }
+
+    pub fn top_n_guesses<'a>(
+        &self,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        self.top_n_guesses_with(Strategy::Entropy, guess_list, word_list, count)
+    }
+
+    /// Like `top_n_guesses`, but ranking guesses by the given strategy's score
+    pub fn top_n_guesses_with<'a>(
+        &self,
+        strategy: Strategy,
+        guess_list: &[&'a str],
+        word_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        let allowed: Vec<&'a str> = word_list
+            .iter()
+            .filter(|word| self.allows(word))
+            .cloned()
+            .collect();
+        rank_guesses(
+            guess_list,
+            count,
+            |guess| strategy.score(&bin_counts(&Peaks, guess, &allowed)),
+            |guess| self.allows(guess),
+        )
+    }
+}
+
+impl Display for PeaksInformation {
+    /// Formats the letters possible at each position, like `[a-m] [e] [p-z]`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, &(first, last)) in self.intervals.iter().enumerate() {
+            if position > 0 {
+                write!(f, " ")?;
+            }
+            if first == last {
+                write!(f, "[{}]", first as char)?;
+            } else {
+                write!(f, "[{}-{}]", first as char, last as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for PeaksInformation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use wordle_engine::{FeedbackModel, WordList, WordleEngine};
+
+    const WORDS: &[&str] = &[
+        "aegis", "favor", "wired", "weird", "wires", "fired", "crane", "trace", "react", "cater",
+        "lymph", "moist", "fluid", "bumpy", "sixty", "knock",
+    ];
+
+    #[test]
+    fn test_intervals() {
+        let mut info = PeaksInformation::new();
+        info.update("moist", &Peaks.feedback("moist", "fired"))
+            .unwrap();
+        assert_eq!(info.to_string(), "[a-l] [a-n] [j-z] [a-r] [a-s]");
+        assert!(info.allows("fired") && !info.allows("weird"));
+        assert_eq!(
+            info.update("aaaaa", &"<<<<<".parse().unwrap()),
+            Err(Contradiction::NoLetters { position: 0 })
+        );
+        assert_eq!(info.interval(0), 'a'..='l');
+    }
+
+    #[test]
+    fn test_solves_peaks() {
+        let words = WordList::from_words(WORDS.iter(), 5).unwrap();
+        for answer in WORDS {
+            let game = WordleEngine::with_answer(words.clone(), answer).with_feedback(Peaks);
+            assert_solves(game, PeaksInformation::new(), WORDS, answer, None);
+        }
+    }
+}
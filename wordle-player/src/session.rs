@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+use wordle_engine::WordleResponse;
+
+use crate::{response_fixes, Contradiction, Information, ResponseFix};
+
+/// The guesses and responses entered while solving a game being played elsewhere. The history is
+/// the source of truth, and the information is rebuilt from it, so any entry can be changed.
+#[derive(Clone, Debug)]
+pub struct Session {
+    base: Information,
+    history: Vec<(String, WordleResponse)>,
+    /// Entries removed by [`Session::undo`], most recent last
+    undone: Vec<(String, WordleResponse)>,
+}
+
+/// The version of the session file format written by [`Session::save`]
+const SESSION_VERSION: u32 = 1;
+
+/// The file format for a saved session
+#[derive(Serialize, Deserialize)]
+struct SavedSession {
+    /// Missing from sessions saved before the format was versioned, which are version 1
+    #[serde(default = "first_version")]
+    version: u32,
+    /// The information known before the first guess, as written by [`Information::pattern`]
+    pattern: Information,
+    /// Each guess with its response, as written by `WordleResponse`'s `Display`
+    history: Vec<(String, String)>,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+impl Session {
+    /// Start a session with the given information known before the first guess
+    pub fn new(base: Information) -> Self {
+        Session {
+            base,
+            history: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// The information known before the first guess
+    pub fn base(&self) -> &Information {
+        &self.base
+    }
+
+    /// The guesses entered so far, along with their responses
+    pub fn history(&self) -> &[(String, WordleResponse)] {
+        &self.history
+    }
+
+    /// The information from every entry, or the index of the first entry which contradicts the
+    /// ones before it
+    pub fn information(&self) -> Result<Information, (usize, Contradiction)> {
+        let mut info = self.base.clone();
+        for (i, (guess, response)) in self.history.iter().enumerate() {
+            info.update(guess, response).map_err(|e| (i, e))?;
+        }
+        Ok(info)
+    }
+
+    /// The single-letter changes to the responses which would leave some of the words allowed
+    pub fn fixes(&self, words: &[&str]) -> Vec<ResponseFix> {
+        response_fixes(&self.base, &self.history, words)
+    }
+
+    /// Add an entry, forgetting any undone entries
+    pub fn push(&mut self, guess: String, response: WordleResponse) {
+        self.history.push((guess, response));
+        self.undone.clear();
+    }
+
+    /// Replace the entry at the given index, returning false if there isn't one
+    pub fn edit(&mut self, index: usize, guess: String, response: WordleResponse) -> bool {
+        match self.history.get_mut(index) {
+            Some(entry) => {
+                *entry = (guess, response);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the last entry, so it can be restored by [`Session::redo`]
+    pub fn undo(&mut self) -> Option<&(String, WordleResponse)> {
+        let entry = self.history.pop()?;
+        self.undone.push(entry);
+        self.undone.last()
+    }
+
+    /// Restore the last entry removed by [`Session::undo`]
+    pub fn redo(&mut self) -> Option<&(String, WordleResponse)> {
+        let entry = self.undone.pop()?;
+        self.history.push(entry);
+        self.history.last()
+    }
+
+    /// Remove every entry
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.undone.clear();
+    }
+
+    /// Write this session as JSON, flushing the writer so that no error goes unreported
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let saved = SavedSession {
+            version: SESSION_VERSION,
+            pattern: self.base.clone(),
+            history: self
+                .history
+                .iter()
+                .map(|(guess, response)| (guess.clone(), response.to_string()))
+                .collect(),
+        };
+        serde_json::to_writer_pretty(&mut writer, &saved)?;
+        writer.flush()
+    }
+
+    /// Read a session written by [`Session::save`]
+    pub fn load<R: Read>(reader: R) -> io::Result<Self> {
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        let saved: SavedSession = serde_json::from_reader(reader)?;
+        if saved.version > SESSION_VERSION {
+            return Err(invalid(format!(
+                "The session was saved in version {} of the format, but only versions up to {} \
+                 are supported",
+                saved.version, SESSION_VERSION
+            )));
+        }
+        let mut session = Session::new(saved.pattern);
+        for (guess, response) in saved.history {
+            let response: WordleResponse = response
+                .parse()
+                .map_err(|e| invalid(format!("Invalid response in session: {}", e)))?;
+            if guess.len() != session.base.word_length()
+                || response.0.len() != session.base.word_length()
+            {
+                return Err(invalid(format!(
+                    "{:?} in session doesn't match the word length {}",
+                    guess,
+                    session.base.word_length()
+                )));
+            }
+            if let Some(c) = guess.chars().find(|c| !c.is_ascii_lowercase()) {
+                return Err(invalid(format!(
+                    "{:?} in session has {:?}, but guesses must be lowercase a-z",
+                    guess, c
+                )));
+            }
+            session.history.push((guess, response));
+        }
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut session = Session::new(Information::new());
+        session.push("sober".to_string(), "!..??".parse().unwrap());
+        session.push("stage".to_string(), "?....".parse().unwrap());
+        assert_eq!(session.information().unwrap_err().0, 1);
+        assert_eq!(
+            session.undo().map(|(guess, _)| guess.as_str()),
+            Some("stage")
+        );
+        assert!(session.information().is_ok());
+        assert_eq!(
+            session.redo().map(|(guess, _)| guess.as_str()),
+            Some("stage")
+        );
+        assert!(session.redo().is_none());
+        assert!(session.edit(1, "stage".to_string(), "!.!.!".parse().unwrap()));
+        assert!(session.information().unwrap().allows("snare"));
+    }
+
+    #[test]
+    fn test_save_load() {
+        let mut session = Session::new("s.... -z".parse().unwrap());
+        session.push("sober".to_string(), "!..??".parse().unwrap());
+        let mut saved = Vec::new();
+        session.save(&mut saved).unwrap();
+        let loaded = Session::load(saved.as_slice()).unwrap();
+        assert_eq!(loaded.history(), session.history());
+        assert_eq!(loaded.base().pattern(), "s.... -z");
+        assert!(
+            Session::load(&b"{\"pattern\": \"s\", \"history\": [[\"sober\", \"!\"]]}"[..]).is_err()
+        );
+        assert!(Session::load(&b"{\"pattern\": \"s\", \"history\": []}"[..]).is_ok());
+        let malformed = br#"{"version":1,"pattern":".....","history":[["SOBER","!..??"]]}"#;
+        let error = Session::load(&malformed[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(
+            Session::load(&b"{\"version\": 2, \"pattern\": \"s\", \"history\": []}"[..]).is_err()
+        );
+    }
+
+    /// A writer which fails once it's asked to write anything
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("No space left"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_save_reports_buffered_errors() {
+        let mut session = Session::new(Information::new());
+        session.push("sober".to_string(), "!..??".parse().unwrap());
+        assert!(session.save(io::BufWriter::new(Full)).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use wordle_engine::{Standard, WordleEngine, WordleResponse, MAX_GUESSES};
+
+use crate::{bin_counts, partition, Information, Strategy};
+
+/// Plays games by always making the best guess under a strategy, to see how the strategy performs
+/// over a list of answers.
+#[derive(Clone, Debug)]
+pub struct Solver<'a> {
+    guess_list: &'a [&'a str],
+    strategy: Strategy,
+    hard_mode: bool,
+    opener: Option<&'a str>,
+    search: TreeSearch,
+}
+
+/// How the solver builds its decision tree in [`Solver::word_paths`]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TreeSearch {
+    /// Always make the best guess under the strategy
+    #[default]
+    Greedy,
+    /// Try each of the `breadth` best guesses under the strategy, keeping whichever needs the
+    /// fewest guesses over the whole subtree. This finds the optimal tree when `breadth` covers
+    /// every useful guess, but the cost grows exponentially with the depth of the tree.
+    Optimal { breadth: usize },
+}
+
+impl<'a> Solver<'a> {
+    /// Create a solver which picks guesses from the given list using the entropy strategy
+    pub fn new(guess_list: &'a [&'a str]) -> Self {
+        Solver {
+            guess_list,
+            strategy: Strategy::default(),
+            hard_mode: false,
+            opener: None,
+            search: TreeSearch::default(),
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Only make guesses which are legal in hard mode
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Always open with the given word, instead of the best one under the strategy
+    pub fn with_opener(mut self, opener: &'a str) -> Self {
+        self.opener = Some(opener);
+        self
+    }
+
+    /// Choose how the decision tree is built by [`Solver::word_paths`]
+    pub fn with_search(mut self, search: TreeSearch) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// The guess to make after `turn` guesses have already been made, where `remaining` is
+    /// exactly the words allowed by `info`.
+    pub fn next_guess(&self, info: &Information, remaining: &[&'a str], turn: usize) -> &'a str {
+        if remaining.len() == 1 {
+            return remaining[0];
+        }
+        if let (0, Some(opener)) = (turn, self.opener) {
+            return opener;
+        }
+        let candidates = self.candidates(info);
+        if candidates.is_empty() {
+            return remaining[0];
+        }
+        let guess = info.get_ideal_guess_with(self.strategy, remaining, &candidates);
+        if makes_progress(guess, remaining) {
+            guess
+        } else {
+            remaining[0]
+        }
+    }
+
+    /// The guesses worth trying after `turn` guesses, best first, for the tree search
+    fn next_guesses(&self, info: &Information, remaining: &[&'a str], turn: usize) -> Vec<&'a str> {
+        match self.search {
+            TreeSearch::Optimal { breadth }
+                if remaining.len() > 2 && (turn > 0 || self.opener.is_none()) =>
+            {
+                let guesses: Vec<&'a str> = info
+                    .top_n_guesses_with(self.strategy, &self.candidates(info), remaining, breadth)
+                    .into_iter()
+                    .map(|(guess, _)| guess)
+                    .filter(|guess| makes_progress(guess, remaining))
+                    .collect();
+                if guesses.is_empty() {
+                    vec![remaining[0]]
+                } else {
+                    guesses
+                }
+            }
+            _ => vec![self.next_guess(info, remaining, turn)],
+        }
+    }
+
+    /// The words which may be guessed with the given information
+    fn candidates(&self, info: &Information) -> Vec<&'a str> {
+        if self.hard_mode {
+            self.guess_list
+                .iter()
+                .filter(|word| info.hard_mode_allows(word))
+                .cloned()
+                .collect()
+        } else {
+            self.guess_list.to_vec()
+        }
+    }
+
+    /// The guesses this solver makes for the given answer, with their responses, if the answer is
+    /// one of the given possible answers.
+    pub fn solve(&self, answers: &[&'a str], answer: &str) -> Vec<(&'a str, WordleResponse)> {
+        let mut info = Information::with_word_length(answer.len());
+        let mut remaining = answers.to_vec();
+        let mut path = Vec::new();
+        while !remaining.is_empty() {
+            let guess = self.next_guess(&info, &remaining, path.len());
+            let response = WordleEngine::get_response(answer, guess);
+            path.push((guess, response.clone()));
+            if response.is_correct() {
+                break;
+            }
+            info.update(guess, &response)
+                .expect("An answer's response can't be contradictory");
+            remaining.retain(|word| WordleEngine::get_response(word, guess) == response);
+        }
+        path
+    }
+
+    /// The guesses this solver makes for every answer, in the order of the decision tree: answers
+    /// are grouped by the responses they give to each guess.
+    pub fn word_paths(&self, answers: Vec<&'a str>) -> Vec<(&'a str, Vec<&'a str>)> {
+        match answers.first() {
+            Some(word) => {
+                let info = Information::with_word_length(word.len());
+                self.subtree_paths(&info, &answers, 0)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// The paths to each of the remaining words, starting from the guess after `turn`
+    fn subtree_paths(
+        &self,
+        info: &Information,
+        remaining: &[&'a str],
+        turn: usize,
+    ) -> Vec<(&'a str, Vec<&'a str>)> {
+        self.next_guesses(info, remaining, turn)
+            .into_iter()
+            .map(|guess| self.paths_after_guess(info, remaining, turn, guess))
+            // Ties go to the earlier guess, which scored better under the strategy
+            .min_by_key(|paths| paths.iter().map(|(_, path)| path.len()).sum::<usize>())
+            .expect("No guesses to make")
+    }
+
+    fn paths_after_guess(
+        &self,
+        info: &Information,
+        remaining: &[&'a str],
+        turn: usize,
+        guess: &'a str,
+    ) -> Vec<(&'a str, Vec<&'a str>)> {
+        let mut paths = Vec::new();
+        for (response, words) in partition(&Standard, guess, remaining) {
+            if response.is_correct() {
+                paths.push((guess, vec![guess]));
+            } else {
+                let mut info = info.clone();
+                info.update(guess, &response)
+                    .expect("An answer's response can't be contradictory");
+                paths.extend(
+                    self.subtree_paths(&info, &words, turn + 1)
+                        .into_iter()
+                        .map(|(word, path)| (word, [guess].into_iter().chain(path).collect())),
+                );
+            }
+        }
+        paths
+    }
+}
+
+/// Returns false if the guess is outside the remaining words and doesn't split them up, so making
+/// it would never make progress. This can happen if the guess list is missing some answers.
+fn makes_progress(guess: &str, remaining: &[&str]) -> bool {
+    remaining.contains(&guess)
+        || !bin_counts(&Standard, guess, remaining).contains(&remaining.len())
+}
+
+/// Statistics about the number of guesses needed for each of a list of answers
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    /// The number of answers
+    pub answers: usize,
+    pub mean: f64,
+    /// The number of answers which needed each number of guesses
+    pub distribution: BTreeMap<usize, usize>,
+    /// The most guesses needed for any answer
+    pub worst_case: usize,
+    /// The answers which needed the most guesses
+    pub worst_answers: Vec<String>,
+    /// The number of answers which needed more than the allowed number of guesses
+    pub failures: usize,
+}
+
+impl Summary {
+    /// Summarize the guess paths found by [`Solver::word_paths`]
+    pub fn from_paths<S: AsRef<str>>(paths: &[(&str, Vec<S>)]) -> Self {
+        let mut distribution = BTreeMap::new();
+        for (_, path) in paths {
+            *distribution.entry(path.len()).or_insert(0) += 1;
+        }
+        let worst_case = distribution.keys().last().cloned().unwrap_or(0);
+        let total: usize = paths.iter().map(|(_, path)| path.len()).sum();
+        Summary {
+            answers: paths.len(),
+            mean: total as f64 / paths.len().max(1) as f64,
+            worst_case,
+            worst_answers: paths
+                .iter()
+                .filter(|(_, path)| path.len() == worst_case)
+                .map(|(word, _)| word.to_string())
+                .collect(),
+            failures: distribution
+                .range(MAX_GUESSES + 1..)
+                .map(|(_, count)| count)
+                .sum(),
+            distribution,
+        }
+    }
+}
+
+/// An answer which needed a different number of guesses under two strategies
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Regression {
+    pub answer: String,
+    /// The number of guesses needed by the strategy being compared against
+    pub before: usize,
+    /// The number of guesses needed by the strategy being compared
+    pub after: usize,
+}
+
+/// The number of guesses needed for each answer, from the guess paths found by
+/// [`Solver::word_paths`]
+pub fn guess_counts<S: AsRef<str>>(paths: &[(&str, Vec<S>)]) -> BTreeMap<String, usize> {
+    paths
+        .iter()
+        .map(|(word, path)| (word.to_string(), path.len()))
+        .collect()
+}
+
+/// The answers which need more guesses in `after` than in `before`, in alphabetical order.
+/// Answers missing from either are ignored.
+pub fn regressions(
+    before: &BTreeMap<String, usize>,
+    after: &BTreeMap<String, usize>,
+) -> Vec<Regression> {
+    before
+        .iter()
+        .filter_map(|(answer, &before)| {
+            let after = *after.get(answer)?;
+            (after > before).then(|| Regression {
+                answer: answer.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Answers: {}", self.answers)?;
+        writeln!(f, "Mean guesses: {:.4}", self.mean)?;
+        writeln!(f, "Distribution:")?;
+        for (guesses, count) in &self.distribution {
+            writeln!(f, "\t{}: {}", guesses, count)?;
+        }
+        writeln!(
+            f,
+            "Worst case: {} guesses ({})",
+            self.worst_case,
+            self.worst_answers.join(", ")
+        )?;
+        write!(
+            f,
+            "Failures (more than {} guesses): {}",
+            MAX_GUESSES, self.failures
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: &[&str] = &["aegis", "favor", "wired", "weird", "wires", "fired"];
+
+    #[test]
+    fn test_paths_match_solve() {
+        let solver = Solver::new(WORDS);
+        let paths = solver.word_paths(WORDS.to_vec());
+        assert_eq!(paths.len(), WORDS.len());
+        for (word, path) in &paths {
+            assert_eq!(path.last(), Some(word));
+            let solved: Vec<&str> = solver
+                .solve(WORDS, word)
+                .into_iter()
+                .map(|(guess, _)| guess)
+                .collect();
+            assert_eq!(&solved, path);
+        }
+        let summary = Summary::from_paths(&paths);
+        assert_eq!(summary.distribution.values().sum::<usize>(), WORDS.len());
+        assert_eq!(summary.failures, 0);
+    }
+
+    #[test]
+    fn test_optimal_search_no_worse() {
+        let total = |paths: Vec<(&str, Vec<&str>)>| -> usize {
+            paths.iter().map(|(_, path)| path.len()).sum()
+        };
+        let solver = Solver::new(WORDS).with_strategy(Strategy::Minimax);
+        let greedy = total(solver.word_paths(WORDS.to_vec()));
+        let optimal = solver
+            .with_search(TreeSearch::Optimal {
+                breadth: WORDS.len(),
+            })
+            .word_paths(WORDS.to_vec());
+        assert_eq!(optimal.len(), WORDS.len());
+        assert!(total(optimal) <= greedy);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let before = guess_counts(&[("aegis", vec!["aegis"]), ("favor", vec!["aegis", "favor"])]);
+        let after = guess_counts(&[("aegis", vec!["favor", "aegis"]), ("favor", vec!["favor"])]);
+        assert_eq!(
+            regressions(&before, &after),
+            vec![Regression {
+                answer: "aegis".to_string(),
+                before: 1,
+                after: 2,
+            }]
+        );
+        assert_eq!(regressions(&after, &before).len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A way of scoring guesses by how they split up the remaining words
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Strategy {
+    /// Maximize the expected bits of information gained
+    #[default]
+    Entropy,
+    /// Minimize the number of words left in the worst case
+    Minimax,
+    /// Minimize the expected number of words left
+    ExpectedSize,
+}
+
+impl Strategy {
+    /// Every strategy, in the order they're listed to users
+    pub const ALL: [Strategy; 3] = [Strategy::Entropy, Strategy::Minimax, Strategy::ExpectedSize];
+
+    /// Score a guess from the number of words giving each response, where higher is better.
+    ///
+    /// Entropy scores are the expected bits of information, while the other strategies give the
+    /// negated number of words left, so that they can be compared the same way.
+    pub fn score(self, bins: &[usize]) -> f64 {
+        let total = bins.iter().sum::<usize>() as f64;
+        let nonempty = bins.iter().filter(|&&count| count != 0).map(|&c| c as f64);
+        match self {
+            Strategy::Entropy => {
+                let start_entropy = total.log2();
+                nonempty
+                    .map(|count| {
+                        let final_entropy = count.log2();
+                        (start_entropy - final_entropy) * count / total
+                    })
+                    .sum()
+            }
+            Strategy::Minimax => -nonempty.fold(0.0, f64::max),
+            Strategy::ExpectedSize => -nonempty.map(|count| count * count).sum::<f64>() / total,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Strategy::Entropy => "entropy",
+            Strategy::Minimax => "minimax",
+            Strategy::ExpectedSize => "expected-size",
+        }
+    }
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Serialize for Strategy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Strategy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Strategy::ALL
+            .into_iter()
+            .find(|strategy| strategy.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown strategy {:?}, expected one of: {}",
+                    s,
+                    Strategy::ALL.map(Strategy::name).join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scores() {
+        let bins = [2, 0, 1, 1];
+        assert_eq!(Strategy::Entropy.score(&bins), 1.5);
+        assert_eq!(Strategy::Minimax.score(&bins), -2.0);
+        assert_eq!(Strategy::ExpectedSize.score(&bins), -1.5);
+        assert_eq!("expected-size".parse(), Ok(Strategy::ExpectedSize));
+    }
+}
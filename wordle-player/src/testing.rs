@@ -0,0 +1,177 @@
+//! Playing whole games against the engine, shared by the tests of each solver
+
+use wordle_engine::{
+    FeedbackModel, FibbleEngine, GuessValidator, PeaksResponse, WordleEngine, WordleResponse,
+    XordleEngine,
+};
+
+use crate::{FeedbackInformation, NoisyInformation, PeaksInformation, XordleInformation};
+
+/// A game a solver can play
+pub(crate) trait TestGame {
+    type Pattern;
+
+    fn guess(&mut self, word: &str) -> Option<Self::Pattern>;
+
+    fn is_over(&self) -> bool;
+
+    fn solved(&self) -> bool;
+}
+
+/// What a solver needs to play a [`TestGame`]
+pub(crate) trait TestSolver<'a> {
+    type Pattern;
+
+    /// Returns whether the word could still be the answer, or one of the answers
+    fn allows(&self, word: &str) -> bool;
+
+    /// The best guess from the list, which is also every possible answer
+    fn best_guess(&self, guesses: &[&'a str]) -> &'a str;
+
+    fn update(&mut self, guess: &str, pattern: &Self::Pattern);
+}
+
+/// Play the game to the end with the solver's best guesses, checking that the solver never rules
+/// out the answer and that it wins. The opener, if given, is the first guess instead, for tests
+/// which would otherwise find the same first guess for every answer.
+pub(crate) fn assert_solves<'a, G, S>(
+    mut game: G,
+    mut solver: S,
+    guesses: &[&'a str],
+    answer: &str,
+    opener: Option<&'a str>,
+) where
+    G: TestGame,
+    S: TestSolver<'a, Pattern = G::Pattern>,
+{
+    let mut turn = 0;
+    while !game.is_over() {
+        assert!(solver.allows(answer), "{} was ruled out", answer);
+        let guess = match (turn, opener) {
+            (0, Some(opener)) => opener,
+            _ => solver.best_guess(guesses),
+        };
+        let pattern = game.guess(guess).expect("Solvers only guess from the list");
+        solver.update(guess, &pattern);
+        turn += 1;
+    }
+    assert!(game.solved(), "{} wasn't solved", answer);
+}
+
+impl<F: FeedbackModel, V: GuessValidator> TestGame for WordleEngine<F, V> {
+    type Pattern = F::Pattern;
+
+    fn guess(&mut self, word: &str) -> Option<F::Pattern> {
+        WordleEngine::guess(self, word)
+    }
+
+    fn is_over(&self) -> bool {
+        WordleEngine::is_over(self)
+    }
+
+    fn solved(&self) -> bool {
+        WordleEngine::solved(self)
+    }
+}
+
+impl TestGame for FibbleEngine {
+    type Pattern = WordleResponse;
+
+    fn guess(&mut self, word: &str) -> Option<WordleResponse> {
+        FibbleEngine::guess(self, word)
+    }
+
+    fn is_over(&self) -> bool {
+        FibbleEngine::is_over(self)
+    }
+
+    fn solved(&self) -> bool {
+        FibbleEngine::solved(self)
+    }
+}
+
+impl TestGame for XordleEngine {
+    type Pattern = WordleResponse;
+
+    fn guess(&mut self, word: &str) -> Option<WordleResponse> {
+        XordleEngine::guess(self, word)
+    }
+
+    fn is_over(&self) -> bool {
+        XordleEngine::is_over(self)
+    }
+
+    fn solved(&self) -> bool {
+        XordleEngine::solved(self)
+    }
+}
+
+impl<'a, F> TestSolver<'a> for FeedbackInformation<F>
+where
+    F: FeedbackModel + Sync,
+    F::Pattern: Sync,
+{
+    type Pattern = F::Pattern;
+
+    fn allows(&self, word: &str) -> bool {
+        FeedbackInformation::allows(self, word)
+    }
+
+    fn best_guess(&self, guesses: &[&'a str]) -> &'a str {
+        self.top_n_guesses(guesses, guesses, 1)[0].0
+    }
+
+    fn update(&mut self, guess: &str, pattern: &F::Pattern) {
+        FeedbackInformation::update(self, guess, pattern)
+    }
+}
+
+impl<'a> TestSolver<'a> for NoisyInformation {
+    type Pattern = WordleResponse;
+
+    fn allows(&self, word: &str) -> bool {
+        NoisyInformation::allows(self, word)
+    }
+
+    fn best_guess(&self, guesses: &[&'a str]) -> &'a str {
+        self.top_n_guesses(guesses, guesses, 1)[0].0
+    }
+
+    fn update(&mut self, guess: &str, response: &WordleResponse) {
+        NoisyInformation::update(self, guess, response)
+    }
+}
+
+impl<'a> TestSolver<'a> for PeaksInformation {
+    type Pattern = PeaksResponse;
+
+    fn allows(&self, word: &str) -> bool {
+        PeaksInformation::allows(self, word)
+    }
+
+    fn best_guess(&self, guesses: &[&'a str]) -> &'a str {
+        self.top_n_guesses(guesses, guesses, 1)[0].0
+    }
+
+    fn update(&mut self, guess: &str, response: &PeaksResponse) {
+        PeaksInformation::update(self, guess, response)
+            .expect("The answer's responses can't be contradictory")
+    }
+}
+
+impl<'a> TestSolver<'a> for XordleInformation<'a> {
+    type Pattern = WordleResponse;
+
+    fn allows(&self, word: &str) -> bool {
+        self.pairs()
+            .any(|(first, second)| first == word || second == word)
+    }
+
+    fn best_guess(&self, guesses: &[&'a str]) -> &'a str {
+        self.top_n_guesses(guesses, 1)[0].0
+    }
+
+    fn update(&mut self, guess: &str, response: &WordleResponse) {
+        XordleInformation::update(self, guess, response)
+    }
+}
@@ -0,0 +1,161 @@
+use wordle_engine::{WordleResponse, XordleEngine};
+
+use crate::{get_bin, rank_guesses, Strategy};
+
+/// What's known about the two answers of a game of Xordle, kept as every pair of words which
+/// could still be the answers.
+///
+/// There can be hundreds of thousands of pairs, so each is kept as two indices into the word
+/// list. Since the answers share no letters, at most one of them colours each tile, so the
+/// response to a guess for a pair is found by adding the responses for its words as
+/// [`WordleResponse::index`] values, without any carries.
+#[derive(Clone, Debug)]
+pub struct XordleInformation<'a> {
+    words: Vec<&'a str>,
+    /// Each pair which could be the answers, lower index first
+    pairs: Vec<(u32, u32)>,
+    /// The guesses made so far
+    guessed: Vec<String>,
+}
+
+impl<'a> XordleInformation<'a> {
+    /// Start with every pair of words from the list which share no letters, leaving out words
+    /// with characters outside `a` to `z`
+    pub fn new(words: &[&'a str]) -> Self {
+        let masks: Vec<Option<u32>> = words
+            .iter()
+            .map(|word| XordleEngine::letters(word))
+            .collect();
+        let pairs = (0..words.len())
+            .flat_map(|i| {
+                let masks = &masks;
+                (i + 1..words.len())
+                    .filter(
+                        move |&j| matches!((masks[i], masks[j]), (Some(a), Some(b)) if a & b == 0),
+                    )
+                    .map(move |j| (i as u32, j as u32))
+            })
+            .collect();
+        XordleInformation {
+            words: words.to_vec(),
+            pairs,
+            guessed: Vec::new(),
+        }
+    }
+
+    /// The number of pairs which could be the answers
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Every pair of words which could be the answers
+    pub fn pairs(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.pairs
+            .iter()
+            .map(|&(i, j)| (self.words[i as usize], self.words[j as usize]))
+    }
+
+    /// The words in any pair which could be the answers, and haven't been guessed yet
+    pub fn answers(&self) -> Vec<&'a str> {
+        let mut possible = vec![false; self.words.len()];
+        for &(i, j) in &self.pairs {
+            possible[i as usize] = true;
+            possible[j as usize] = true;
+        }
+        self.words
+            .iter()
+            .zip(possible)
+            .filter(|(word, possible)| *possible && !self.guessed.iter().any(|g| g == *word))
+            .map(|(word, _)| *word)
+            .collect()
+    }
+
+    /// The index of the response to the guess for each word in the list
+    fn word_bins(&self, guess: &str) -> Vec<usize> {
+        self.words.iter().map(|word| get_bin(guess, word)).collect()
+    }
+
+    /// Keep the pairs which would have given this response to the guess
+    pub fn update(&mut self, guess: &str, response: &WordleResponse) {
+        let bins = self.word_bins(guess);
+        let index = response.index();
+        self.pairs
+            .retain(|&(i, j)| bins[i as usize] + bins[j as usize] == index);
+        self.guessed.push(guess.to_string());
+    }
+
+    /// The number of pairs which would give each response to the guess
+    fn bin_counts(&self, guess: &str) -> Vec<usize> {
+        let bins = self.word_bins(guess);
+        let mut counts = vec![0; 3usize.pow(guess.len() as u32)];
+        for &(i, j) in &self.pairs {
+            counts[bins[i as usize] + bins[j as usize]] += 1;
+        }
+        counts
+    }
+
+    /// Returns the expected bits of entropy gained by this guess
+    pub fn evaluate_guess(&self, guess: &str) -> f64 {
+        Strategy::Entropy.score(&self.bin_counts(guess))
+    }
+
+    pub fn top_n_guesses(&self, guess_list: &[&'a str], count: usize) -> Vec<(&'a str, f64)> {
+        self.top_n_guesses_with(Strategy::Entropy, guess_list, count)
+    }
+
+    /// Like `top_n_guesses`, but ranking guesses by the given strategy's score. Words which could
+    /// be an answer not yet guessed win ties.
+    pub fn top_n_guesses_with(
+        &self,
+        strategy: Strategy,
+        guess_list: &[&'a str],
+        count: usize,
+    ) -> Vec<(&'a str, f64)> {
+        let answers = self.answers();
+        rank_guesses(
+            guess_list,
+            count,
+            |guess| strategy.score(&self.bin_counts(guess)),
+            |guess| answers.contains(&guess),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_solves;
+    use wordle_engine::WordList;
+
+    const WORDS: &[&str] = &[
+        "crane", "moist", "lymph", "trace", "bumpy", "sixty", "dowdy", "fluid", "knock", "glyph",
+    ];
+
+    #[test]
+    fn test_pair_responses() {
+        let mut info = XordleInformation::new(WORDS);
+        assert!(info
+            .pairs()
+            .all(|(a, b)| XordleEngine::share_no_letters(a, b)));
+        let response = XordleEngine::get_response(["crane", "moist"], "trace");
+        info.update("trace", &response);
+        assert!(info.pairs().any(|pair| pair == ("crane", "moist")));
+        assert!(info
+            .pairs()
+            .all(|(a, b)| XordleEngine::get_response([a, b], "trace") == response));
+    }
+
+    #[test]
+    fn test_solves_xordle() {
+        let words = WordList::from_words(WORDS.iter(), 5).unwrap();
+        let pairs: Vec<(&str, &str)> = XordleInformation::new(WORDS).pairs().collect();
+        for (first, second) in pairs {
+            let game = XordleEngine::with_answers(words.clone(), first, second).unwrap();
+            assert_solves(game, XordleInformation::new(WORDS), WORDS, first, None);
+        }
+    }
+}